@@ -0,0 +1,61 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Clone, Debug)]
+pub struct BooleanLiteral {
+    pub token: Token,
+    pub value: bool,
+}
+
+impl BooleanLiteral {
+    pub fn new(token: Token, value: bool) -> Self {
+        Self { token, value }
+    }
+}
+
+impl Node for BooleanLiteral {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for BooleanLiteral {
+    fn to_string(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl Expression for BooleanLiteral {
+    fn eval(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+
+    #[test]
+    pub fn test_boolean_literal_expression() {
+        let code = r#"
+            true;
+            false;
+            !true;
+            true == !false;
+            a < b == false;
+        "#;
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "true",
+                "false",
+                "(!true)",
+                "(true == (!false))",
+                "((a < b) == false)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Clone, Debug)]
+pub struct CharLiteral {
+    pub token: Token,
+    pub value: char,
+}
+
+impl CharLiteral {
+    pub fn new(token: Token, value: char) -> Self {
+        Self { token, value }
+    }
+}
+
+impl Node for CharLiteral {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for CharLiteral {
+    fn to_string(&self) -> String {
+        format!("{:?}", self.value)
+    }
+}
+
+impl Expression for CharLiteral {
+    fn eval(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexer::Lexer,
+        parser::{error::ParserErrorCode, Parser},
+    };
+
+    #[test]
+    pub fn test_char_literal_expression() {
+        let code = r"let c = 'a'; '\n';";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse();
+
+        assert!(program.is_ok());
+
+        let statements = program.unwrap().statements;
+        assert_eq!(statements[0].to_string(), "let c = 'a';");
+        assert_eq!(statements[1].to_string(), r"'\n'");
+    }
+
+    #[test]
+    pub fn test_invalid_char_literal_expression() {
+        let code = "let c = 'ab';";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err());
+
+        // The lexer reports the literal first, then the parser refuses to build it.
+        let error = &parser.errors()[1];
+        assert_eq!(error.code(), &ParserErrorCode::InvalidChar);
+        assert_eq!(error.message(), "Invalid char literal: 'ab'");
+        assert_eq!(
+            error.span().unwrap().underline(code),
+            "let c = 'ab';\n        ^^^^"
+        );
+    }
+}
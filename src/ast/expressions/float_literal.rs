@@ -0,0 +1,55 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Clone, Debug)]
+pub struct FloatLiteral {
+    pub token: Token,
+    pub value: f64,
+}
+
+impl FloatLiteral {
+    pub fn new(token: Token, value: f64) -> Self {
+        Self { token, value }
+    }
+}
+
+impl Node for FloatLiteral {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for FloatLiteral {
+    fn to_string(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl Expression for FloatLiteral {
+    fn eval(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::Lexer, parser::Parser};
+
+    #[test]
+    pub fn test_float_literal_expression() {
+        let code = "3.14; 1e-3; 2.5f32;";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse();
+
+        assert!(program.is_ok());
+
+        let statements = program.unwrap().statements;
+        assert_eq!(statements[0].to_string(), "3.14");
+        assert_eq!(statements[1].to_string(), "0.001");
+        assert_eq!(statements[2].to_string(), "2.5");
+    }
+}
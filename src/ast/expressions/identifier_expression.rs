@@ -1,4 +1,5 @@
 use crate::{
+    interner::Symbol,
     token::Token,
     traits::{Expression, Node},
 };
@@ -6,12 +7,12 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct Identifier {
     pub token: Token,
-    pub value: String,
+    pub symbol: Symbol,
 }
 
 impl Identifier {
-    pub fn new(token: Token, value: String) -> Self {
-        Self { token, value }
+    pub fn new(token: Token, symbol: Symbol) -> Self {
+        Self { token, symbol }
     }
 }
 
@@ -23,7 +24,7 @@ impl Node for Identifier {
 
 impl ToString for Identifier {
     fn to_string(&self) -> String {
-        self.token.value.clone()
+        self.symbol.to_string()
     }
 }
 
@@ -31,11 +32,15 @@ impl Expression for Identifier {
     fn eval(&self) -> String {
         "".to_owned()
     }
+
+    fn is_assignable(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{lexer::Lexer, parser::Parser};
+    use crate::{interner::Symbol, lexer::Lexer, parser::Parser};
 
     #[test]
     pub fn test_identifier_expression() {
@@ -44,7 +49,7 @@ mod tests {
             var name: String;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let program = parser.parse();
 
@@ -54,4 +59,14 @@ mod tests {
 
         assert_eq!(statements.len(), 2);
     }
+
+    #[test]
+    pub fn test_identifier_symbol() {
+        let mut lexer = Lexer::new("counter;");
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse().unwrap();
+
+        assert_eq!(program.statements[0].to_string(), "counter");
+        assert_eq!(Symbol::get("counter").map(Symbol::as_str), Some("counter"));
+    }
 }
@@ -0,0 +1,140 @@
+use crate::{
+    ast::statements::block_statement::BlockStatement,
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Debug)]
+pub enum ElseBranch {
+    Block(BlockStatement),
+    If(Box<IfExpression>),
+}
+
+#[derive(Debug)]
+pub struct IfExpression {
+    pub token: Token,
+    pub condition: Box<dyn Expression>,
+    pub consequence: BlockStatement,
+    pub alternative: Option<ElseBranch>,
+}
+
+impl IfExpression {
+    pub fn new(
+        token: Token,
+        condition: Box<dyn Expression>,
+        consequence: BlockStatement,
+        alternative: Option<ElseBranch>,
+    ) -> Self {
+        Self {
+            token,
+            condition,
+            consequence,
+            alternative,
+        }
+    }
+}
+
+impl Node for IfExpression {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for IfExpression {
+    fn to_string(&self) -> String {
+        let mut s = format!(
+            "if {} {}",
+            self.condition.to_string(),
+            self.consequence.to_string()
+        );
+
+        match &self.alternative {
+            Some(ElseBranch::Block(block)) => s.push_str(&format!(" else {}", block.to_string())),
+            Some(ElseBranch::If(expression)) => {
+                s.push_str(&format!(" else {}", expression.to_string()))
+            }
+            None => {}
+        }
+
+        s
+    }
+}
+
+impl Expression for IfExpression {
+    fn eval(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexer::Lexer,
+        parser::{error::ParserErrorCode, parse_statements, Parser},
+    };
+
+    #[test]
+    pub fn test_if_expression() {
+        let code = r#"
+            if x < y { x }
+            if (x > 0) { x } else { -x }
+            let parity = if i % 2 == 0 { `even` } else if i % 3 == 0 { `odd` } else { `other` };
+            if done {}
+            return if a { b; c } else { d };
+        "#;
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "if (x < y) { x }",
+                "if (x > 0) { x } else { (-x) }",
+                "let parity = if ((i % 2) == 0) { `even` } else if ((i % 3) == 0) { `odd` } \
+                 else { `other` };",
+                "if done {}",
+                "return if a { b c } else { d };",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_if_statement_ends_expression() {
+        let code = r#"
+            if a { b } else { c }
+            -x;
+            let y = if x { 1 } else { 2 } + 3;
+            { if a { b } else { c } -x }
+        "#;
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "if a { b } else { c }",
+                "(-x)",
+                "let y = (if x { 1 } else { 2 } + 3);",
+                "{ if a { b } else { c } (-x) }",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_if_expression_errors() {
+        let tests = [
+            ("if x y", ParserErrorCode::UnexpectedToken),
+            ("if x { y", ParserErrorCode::UnexpectedToken),
+            ("if x { y } else z", ParserErrorCode::UnexpectedToken),
+            ("else { y }", ParserErrorCode::DanglingElse),
+            (
+                "if x { 1 } else { 2 } * 3;",
+                ParserErrorCode::InvalidExpression,
+            ),
+        ];
+
+        for (code, expected) in tests {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+            assert_eq!(parser.errors()[0].code(), &expected, "{}", code);
+        }
+    }
+}
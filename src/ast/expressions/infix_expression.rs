@@ -0,0 +1,205 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Debug)]
+pub struct InfixExpression {
+    pub token: Token,
+    pub lhs: Box<dyn Expression>,
+    pub operator: String,
+    pub rhs: Box<dyn Expression>,
+}
+
+impl InfixExpression {
+    pub fn new(
+        token: Token,
+        lhs: Box<dyn Expression>,
+        operator: String,
+        rhs: Box<dyn Expression>,
+    ) -> Self {
+        Self {
+            token,
+            lhs,
+            operator,
+            rhs,
+        }
+    }
+}
+
+impl Node for InfixExpression {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for InfixExpression {
+    fn to_string(&self) -> String {
+        format!(
+            "({} {} {})",
+            self.lhs.to_string(),
+            self.operator,
+            self.rhs.to_string()
+        )
+    }
+}
+
+impl Expression for InfixExpression {
+    fn eval(&self) -> String {
+        self.operator.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexer::Lexer,
+        parser::{error::ParserErrorCode, parse_statements, Parser},
+    };
+
+    fn parse_error(code: &str) -> ParserErrorCode {
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err(), "{}", code);
+
+        parser.errors()[0].code().clone()
+    }
+
+    #[test]
+    pub fn test_infix_expression() {
+        let operators = [
+            "+", "-", "*", "/", "%", "**", "==", "!=", "<", ">", "<=", ">=", "&", "|", "^", "<<",
+            ">>", "&&", "||",
+        ];
+
+        for operator in operators {
+            let code = format!("a {} b;", operator);
+
+            assert_eq!(parse_statements(&code), vec![format!("(a {} b)", operator)]);
+        }
+    }
+
+    #[test]
+    pub fn test_operator_precedence() {
+        let tests = [
+            ("a * b - c", "((a * b) - c)"),
+            ("a + b - c", "((a + b) - c)"),
+            ("a + b * c + d / e - f", "(((a + (b * c)) + (d / e)) - f)"),
+            ("-a * b", "((-a) * b)"),
+            ("!-a", "(!(-a))"),
+            ("a ** b * c", "((a ** b) * c)"),
+            ("5 > 4 == 3 < 4", "((5 > 4) == (3 < 4))"),
+            ("x % 2 == 0", "((x % 2) == 0)"),
+            ("a || b && c", "(a || (b && c))"),
+            ("a && b == c || d", "((a && (b == c)) || d)"),
+            ("a | b ^ c & d", "(a | (b ^ (c & d)))"),
+            ("a & b == c", "((a & b) == c)"),
+            ("a << 1 + b", "(a << (1 + b))"),
+            ("a >> b < c", "((a >> b) < c)"),
+        ];
+
+        for (code, expected) in tests {
+            assert_eq!(
+                parse_statements(code),
+                vec![expected.to_owned()],
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_infix_expression_statements() {
+        assert_eq!(
+            parse_statements("a + b; c * d\ne - f"),
+            vec!["(a + b)", "(c * d)", "(e - f)"]
+        );
+    }
+
+    #[test]
+    pub fn test_grouped_expressions() {
+        let tests = [
+            ("(a + b) * c", "((a + b) * c)"),
+            ("a * (b - c)", "(a * (b - c))"),
+            ("-(a + b)", "(-(a + b))"),
+            ("((a))", "a"),
+            ("(a < b) < c", "((a < b) < c)"),
+            ("a - (b - c)", "(a - (b - c))"),
+            ("`${(a + b) * 2}`", "`${((a + b) * 2)}`"),
+        ];
+
+        for (code, expected) in tests {
+            assert_eq!(
+                parse_statements(code),
+                vec![expected.to_owned()],
+                "{}",
+                code
+            );
+        }
+
+        assert_eq!(parse_error("(a + b"), ParserErrorCode::UnexpectedToken);
+        assert_eq!(parse_error("()"), ParserErrorCode::InvalidExpression);
+    }
+
+    #[test]
+    pub fn test_associativity() {
+        let tests = [
+            ("a - b - c", "((a - b) - c)"),
+            ("a / b * c", "((a / b) * c)"),
+            ("a ** b ** c", "(a ** (b ** c))"),
+            ("a = b = c", "(a = (b = c))"),
+            ("y -= 1", "(y -= 1)"),
+            ("a = b + c * d", "(a = (b + (c * d)))"),
+            ("a += b = c || d", "(a += (b = (c || d)))"),
+            ("a < b == c < d", "((a < b) == (c < d))"),
+        ];
+
+        for (code, expected) in tests {
+            assert_eq!(
+                parse_statements(code),
+                vec![expected.to_owned()],
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_non_associative_comparisons() {
+        for code in ["a < b < c", "a == b != c", "a >= b <= c", "1 < 2 > 3"] {
+            assert_eq!(
+                parse_error(code),
+                ParserErrorCode::NonAssociativeOperator,
+                "{}",
+                code
+            );
+        }
+
+        let mut lexer = Lexer::new("a < b < c;");
+        let mut parser = Parser::new(&mut lexer);
+        assert!(parser.parse().is_err());
+
+        let error = &parser.errors()[0];
+        assert_eq!(
+            error.message(),
+            "Operator '<' cannot be chained with '<', use parentheses"
+        );
+        assert_eq!(
+            error.span().unwrap().underline("a < b < c;"),
+            "a < b < c;\n      ^"
+        );
+    }
+
+    #[test]
+    pub fn test_invalid_assignment_target() {
+        for code in ["1 = a", "a + b = c", "-a = b", "(a = b) = c"] {
+            assert_eq!(
+                parse_error(code),
+                ParserErrorCode::InvalidAssignmentTarget,
+                "{}",
+                code
+            );
+        }
+    }
+}
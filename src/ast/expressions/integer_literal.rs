@@ -1,17 +1,25 @@
 use crate::{
+    enums::number_suffix::NumberSuffix,
     token::Token,
     traits::{Expression, Node},
 };
 
+/// An integer literal. The value is kept unsigned and as wide as any suffix allows, the lexer
+/// having checked it fits the suffix (one past the signed maximum, so it can be negated).
 #[derive(Clone, Debug)]
 pub struct IntegerLiteral {
     pub token: Token,
-    pub value: i32,
+    pub value: u128,
+    pub suffix: Option<NumberSuffix>,
 }
 
 impl IntegerLiteral {
-    pub fn new(token: Token, value: i32) -> Self {
-        Self { token, value }
+    pub fn new(token: Token, value: u128, suffix: Option<NumberSuffix>) -> Self {
+        Self {
+            token,
+            value,
+            suffix,
+        }
     }
 }
 
@@ -23,7 +31,10 @@ impl Node for IntegerLiteral {
 
 impl ToString for IntegerLiteral {
     fn to_string(&self) -> String {
-        self.value.to_string()
+        match self.suffix {
+            Some(suffix) => format!("{}{}", self.value, suffix.as_str().to_lowercase()),
+            None => self.value.to_string(),
+        }
     }
 }
 
@@ -35,7 +46,10 @@ impl Expression for IntegerLiteral {
 
 #[cfg(test)]
 mod tests {
-    use crate::{lexer::Lexer, parser::Parser};
+    use crate::{
+        lexer::Lexer,
+        parser::{error::ParserErrorCode, parse_statements, Parser},
+    };
 
     #[test]
     pub fn test_integer_literal_expression() {
@@ -43,7 +57,7 @@ mod tests {
             5;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let program = parser.parse();
 
@@ -52,4 +66,54 @@ mod tests {
 
         assert_eq!(program.unwrap().statements.len(), 1);
     }
+
+    #[test]
+    pub fn test_suffixed_integer_literal_expression() {
+        let code = "10u8;";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse();
+
+        assert!(program.is_ok());
+
+        assert_eq!(program.unwrap().statements[0].to_string(), "10u8");
+    }
+
+    #[test]
+    pub fn test_wide_integer_literal_expression() {
+        let code = "2147483648; -2147483648; 5000000000u64; 0xFFFF_FFFFu32;";
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "2147483648",
+                "(-2147483648)",
+                "5000000000u64",
+                "4294967295u32"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_invalid_integer_literal_expression() {
+        for literal in ["0b102", "300u8", "2147483649", "3000000000"] {
+            let code = format!("{};", literal);
+            let mut lexer = Lexer::new(&code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+
+            // The lexer reports the bad literal, then the parser refuses to build it.
+            let errors = parser.errors();
+            assert_eq!(errors[0].code(), &ParserErrorCode::Lexical, "{}", code);
+            assert_eq!(
+                errors[1].code(),
+                &ParserErrorCode::InvalidNumber,
+                "{}",
+                code
+            );
+            assert_eq!(errors[1].token().unwrap().value, literal);
+        }
+    }
 }
@@ -1,3 +1,10 @@
+pub mod boolean_literal;
+pub mod char_literal;
+pub mod float_literal;
 pub mod identifier_expression;
+pub mod if_expression;
+pub mod infix_expression;
 pub mod integer_literal;
 pub mod prefix_expression;
+pub mod string_literal;
+pub mod template_literal;
@@ -47,7 +47,7 @@ mod tests {
             -!-!-!-!-!-10000;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let program = parser.parse();
 
@@ -0,0 +1,54 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Clone, Debug)]
+pub struct StringLiteral {
+    pub token: Token,
+    pub value: String,
+}
+
+impl StringLiteral {
+    pub fn new(token: Token, value: String) -> Self {
+        Self { token, value }
+    }
+}
+
+impl Node for StringLiteral {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for StringLiteral {
+    fn to_string(&self) -> String {
+        format!("{:?}", self.value)
+    }
+}
+
+impl Expression for StringLiteral {
+    fn eval(&self) -> String {
+        self.value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::Lexer, parser::Parser};
+
+    #[test]
+    pub fn test_string_literal_expression() {
+        let code = r#"let s = "hi"; "tab\tquote\"";"#;
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse();
+
+        assert!(program.is_ok());
+
+        let statements = program.unwrap().statements;
+        assert_eq!(statements[0].to_string(), r#"let s = "hi";"#);
+        assert_eq!(statements[1].to_string(), r#""tab\tquote\"""#);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node},
+};
+
+#[derive(Debug)]
+pub enum TemplatePart {
+    Text(String),                    // Literal text between interpolations
+    Expression(Box<dyn Expression>), // An interpolated `${...}` expression
+}
+
+/// A backtick template string such as `Hello ${name}`.
+#[derive(Debug)]
+pub struct TemplateLiteral {
+    pub token: Token,
+    pub parts: Vec<TemplatePart>,
+}
+
+impl TemplateLiteral {
+    pub fn new(token: Token, parts: Vec<TemplatePart>) -> Self {
+        Self { token, parts }
+    }
+}
+
+impl Node for TemplateLiteral {
+    fn get_token(&self) -> String {
+        self.token.value.clone()
+    }
+}
+
+impl ToString for TemplateLiteral {
+    fn to_string(&self) -> String {
+        let mut s = String::from("`");
+
+        for part in &self.parts {
+            match part {
+                TemplatePart::Text(text) => s.push_str(text),
+                TemplatePart::Expression(expression) => {
+                    s.push_str(format!("${{{}}}", expression.to_string()).as_str())
+                }
+            }
+        }
+
+        s.push('`');
+
+        s
+    }
+}
+
+impl Expression for TemplateLiteral {
+    fn eval(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TemplatePart::Text(text) => text.clone(),
+                TemplatePart::Expression(expression) => expression.eval(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::Lexer, parser::Parser};
+
+    #[test]
+    pub fn test_template_literal_expression() {
+        let code = r#"
+            `Hello ${name}, you are ${-age}!`;
+            ``;
+            `no interpolation`;
+            `next year: ${age + 1}`;
+        "#;
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse();
+
+        assert!(program.is_ok());
+
+        let statements = program.unwrap().statements;
+        assert_eq!(statements.len(), 4);
+        assert_eq!(
+            statements[0].to_string(),
+            "`Hello ${name}, you are ${(-age)}!`"
+        );
+        assert_eq!(statements[1].to_string(), "``");
+        assert_eq!(statements[2].to_string(), "`no interpolation`");
+        assert_eq!(statements[3].to_string(), "`next year: ${(age + 1)}`");
+    }
+
+    #[test]
+    pub fn test_unterminated_template_literal() {
+        let code = "`Hello ${name}";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err());
+    }
+}
@@ -1,3 +1,6 @@
+// `Node` requires `ToString`, so every AST node implements it directly.
+#![allow(clippy::to_string_trait_impl)]
+
 pub mod program;
 pub mod statements;
 pub mod expressions;
@@ -0,0 +1,99 @@
+use crate::{
+    token::Token,
+    traits::{Expression, Node, Statement},
+};
+
+pub struct BlockStatement {
+    pub token: Token,
+    pub statements: Vec<Box<dyn Statement>>,
+    // The expression ending the block without a semicolon, which the block evaluates to.
+    pub value: Option<Box<dyn Expression>>,
+}
+
+impl BlockStatement {
+    pub fn new(
+        token: Token,
+        statements: Vec<Box<dyn Statement>>,
+        value: Option<Box<dyn Expression>>,
+    ) -> Self {
+        Self {
+            token,
+            statements,
+            value,
+        }
+    }
+}
+
+impl core::fmt::Debug for BlockStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BlockStatement{{{}}}", self.to_string())
+    }
+}
+
+impl Node for BlockStatement {
+    fn get_token(&self) -> String {
+        self.token.value.to_string()
+    }
+}
+
+impl ToString for BlockStatement {
+    fn to_string(&self) -> String {
+        let mut parts: Vec<String> = self.statements.iter().map(|s| s.to_string()).collect();
+
+        if let Some(v) = &self.value {
+            parts.push(v.to_string());
+        }
+
+        if parts.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", parts.join(" "))
+        }
+    }
+}
+
+impl Statement for BlockStatement {
+    fn execute(&self) {
+        for statement in &self.statements {
+            statement.execute();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lexer::Lexer,
+        parser::{parse_statements, Parser},
+    };
+
+    #[test]
+    pub fn test_block_statement() {
+        let code = r#"
+            { let x = 1; x + 1 }
+            {}
+            { let y = 2; y; }
+            { { z } }
+        "#;
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "{ let x = 1; (x + 1) }",
+                "{}",
+                "{ let y = 2; y }",
+                "{ { z } }",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_unterminated_block_statement() {
+        let code = "{ let x = 1;";
+
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err());
+    }
+}
@@ -42,14 +42,10 @@ impl Node for DeclareStatement {
 
 impl ToString for DeclareStatement {
     fn to_string(&self) -> String {
-        let mut s = format!(
-            "{} {}",
-            self.token.value.to_string(),
-            self.identifier.to_string()
-        );
+        let mut s = format!("{} {}", self.token.value, self.identifier.to_string());
 
         if let Some(t) = &self.type_specifier {
-            s.push_str(format!(": {}", t.value.to_string()).as_str());
+            s.push_str(format!(": {}", t.value).as_str());
         };
 
         if let Some(v) = &self.value {
@@ -77,37 +73,63 @@ mod tests {
             statements::declare_statement::DeclareStatement,
         },
         enums::{keyword::Keyword, token_type::TokenType},
+        interner::Symbol,
         lexer::Lexer,
-        parser::Parser,
+        parser::{parse_statements, Parser},
+        span::Span,
         token::Token,
     };
 
     #[test]
     pub fn test_declare_statements() {
         let code = r#"let x = 5;
-            let name: string = Abdoulaye Dia;
+            let name: string = "Abdoulaye Dia";
             const WINDOW_WIDTH: uint16_t = 1440;
             let later;
             const PI: float = 3.14;
             let is_active: bool = true;
-            auto is_active = user.is_active();"#;
-
-        let mut lexer = Lexer::new(code.chars().collect());
-        let mut parser = Parser::new(&mut lexer);
-        let program = parser.parse();
-
-        assert!(program.is_ok());
+            let is_visible = !is_banned && false;
+            auto area = WINDOW_WIDTH * height + 1;"#;
+
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "let x = 5;",
+                "let name: string = \"Abdoulaye Dia\";",
+                "const WINDOW_WIDTH: uint16_t = 1440;",
+                "let later;",
+                "const PI: float = 3.14;",
+                "let is_active: bool = true;",
+                "let is_visible = ((!is_banned) && false);",
+                "auto area = ((WINDOW_WIDTH * height) + 1);",
+            ]
+        );
+    }
 
-        assert_eq!(program.unwrap().statements.len(), 7);
+    #[test]
+    pub fn test_declare_statement_errors() {
+        let codes = [
+            "let x = 5",
+            "let x = 5 6;",
+            "let x = ;",
+            "let name = Abdoulaye Dia;",
+        ];
+
+        for code in codes {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+        }
     }
 
     #[test]
     fn test_declare_statement_values() {
-        let token = Token::new(TokenType::KEYWORD(Keyword::LET), "let", None, None, None);
-        let type_specifier = Some(Token::new(TokenType::IDENT, "int", None, None, None));
+        let token = Token::new(TokenType::KEYWORD(Keyword::LET), "let", Span::default());
+        let type_specifier = Some(Token::new(TokenType::IDENT, "int", Span::default()));
         let identifier = Identifier::new(
-            Token::new(TokenType::IDENT, "x", None, None, None),
-            "x".to_string(),
+            Token::new(TokenType::IDENT, "x", Span::default()),
+            Symbol::intern("x"),
         );
 
         let declare_statement =
@@ -22,7 +22,7 @@ impl core::fmt::Debug for ExpressionStatement {
 
 impl Node for ExpressionStatement {
     fn get_token(&self) -> String {
-        format!("return EXPR;")
+        "return EXPR;".to_string()
     }
 }
 
@@ -46,7 +46,7 @@ mod tests {
             foobar;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let program = parser.parse();
 
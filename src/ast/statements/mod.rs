@@ -1,3 +1,4 @@
+pub mod block_statement;
 pub mod declare_statement;
 pub mod return_statement;
 pub mod expression_statement;
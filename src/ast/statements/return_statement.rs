@@ -42,7 +42,10 @@ impl Statement for ReturnStatement {
 
 #[cfg(test)]
 mod tests {
-    use crate::{lexer::Lexer, parser::Parser};
+    use crate::{
+        lexer::Lexer,
+        parser::{parse_statements, Parser},
+    };
 
     #[test]
     pub fn test_return_statement() {
@@ -51,18 +54,33 @@ mod tests {
             return x;
 
 
-            return true;
+            return x * 2 + 1;
             return;
 
             return true;
+            return -x;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
-        let mut parser = Parser::new(&mut lexer);
-        let program = parser.parse();
+        assert_eq!(
+            parse_statements(code),
+            vec![
+                "let x = 0;",
+                "return x;",
+                "return ((x * 2) + 1);",
+                "return;",
+                "return true;",
+                "return (-x);",
+            ]
+        );
+    }
 
-        assert!(program.is_ok());
+    #[test]
+    pub fn test_return_statement_errors() {
+        for code in ["return x", "return x y;", "return"] {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
 
-        assert_eq!(program.unwrap().statements.len(), 5);
+            assert!(parser.parse().is_err(), "{}", code);
+        }
     }
 }
@@ -0,0 +1,104 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AssignOp {
+    PLUS,  // Addition assignment operator (+=)
+    MINUS, // Subtraction assignment operator (-=)
+    MUL,   // Multiplication assignment operator (*=)
+    DIV,   // Division assignment operator (/=)
+    MOD,   // Modulo assignment operator (%=)
+    POW,   // Exponentiation assignment operator (**=)
+    AND,   // Bitwise AND assignment operator (&=)
+    OR,    // Bitwise OR assignment operator (|=)
+    XOR,   // Bitwise XOR assignment operator (^=)
+    SHL,   // Left shift assignment operator (<<=)
+    SHR,   // Right shift assignment operator (>>=)
+}
+
+impl AssignOp {
+    /// Returns the string representation of the AssignOp variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssignOp::PLUS => "PLUS_ASSIGN",
+            AssignOp::MINUS => "MINUS_ASSIGN",
+            AssignOp::MUL => "MUL_ASSIGN",
+            AssignOp::DIV => "DIV_ASSIGN",
+            AssignOp::MOD => "MOD_ASSIGN",
+            AssignOp::POW => "POW_ASSIGN",
+            AssignOp::AND => "AND_ASSIGN",
+            AssignOp::OR => "OR_ASSIGN",
+            AssignOp::XOR => "XOR_ASSIGN",
+            AssignOp::SHL => "SHL_ASSIGN",
+            AssignOp::SHR => "SHR_ASSIGN",
+        }
+    }
+
+    /// Converts the AssignOp variant to a String.
+    pub fn to_string(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    /// Creates an AssignOp variant from a string representation.
+    pub fn from_str(value: &str) -> Option<AssignOp> {
+        match value {
+            "PLUS_ASSIGN" | "+=" => Some(AssignOp::PLUS),
+            "MINUS_ASSIGN" | "-=" => Some(AssignOp::MINUS),
+            "MUL_ASSIGN" | "*=" => Some(AssignOp::MUL),
+            "DIV_ASSIGN" | "/=" => Some(AssignOp::DIV),
+            "MOD_ASSIGN" | "%=" => Some(AssignOp::MOD),
+            "POW_ASSIGN" | "**=" => Some(AssignOp::POW),
+            "AND_ASSIGN" | "&=" => Some(AssignOp::AND),
+            "OR_ASSIGN" | "|=" => Some(AssignOp::OR),
+            "XOR_ASSIGN" | "^=" => Some(AssignOp::XOR),
+            "SHL_ASSIGN" | "<<=" => Some(AssignOp::SHL),
+            "SHR_ASSIGN" | ">>=" => Some(AssignOp::SHR),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(AssignOp::PLUS.as_str(), "PLUS_ASSIGN");
+        assert_eq!(AssignOp::MINUS.as_str(), "MINUS_ASSIGN");
+        assert_eq!(AssignOp::MUL.as_str(), "MUL_ASSIGN");
+        assert_eq!(AssignOp::DIV.as_str(), "DIV_ASSIGN");
+        assert_eq!(AssignOp::MOD.as_str(), "MOD_ASSIGN");
+        assert_eq!(AssignOp::POW.as_str(), "POW_ASSIGN");
+        assert_eq!(AssignOp::AND.as_str(), "AND_ASSIGN");
+        assert_eq!(AssignOp::OR.as_str(), "OR_ASSIGN");
+        assert_eq!(AssignOp::XOR.as_str(), "XOR_ASSIGN");
+        assert_eq!(AssignOp::SHL.as_str(), "SHL_ASSIGN");
+        assert_eq!(AssignOp::SHR.as_str(), "SHR_ASSIGN");
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(AssignOp::PLUS.to_string(), "PLUS_ASSIGN");
+        assert_eq!(AssignOp::POW.to_string(), "POW_ASSIGN");
+        assert_eq!(AssignOp::SHR.to_string(), "SHR_ASSIGN");
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(AssignOp::from_str("+="), Some(AssignOp::PLUS));
+        assert_eq!(AssignOp::from_str("-="), Some(AssignOp::MINUS));
+        assert_eq!(AssignOp::from_str("*="), Some(AssignOp::MUL));
+        assert_eq!(AssignOp::from_str("/="), Some(AssignOp::DIV));
+        assert_eq!(AssignOp::from_str("%="), Some(AssignOp::MOD));
+        assert_eq!(AssignOp::from_str("**="), Some(AssignOp::POW));
+        assert_eq!(AssignOp::from_str("&="), Some(AssignOp::AND));
+        assert_eq!(AssignOp::from_str("|="), Some(AssignOp::OR));
+        assert_eq!(AssignOp::from_str("^="), Some(AssignOp::XOR));
+        assert_eq!(AssignOp::from_str("<<="), Some(AssignOp::SHL));
+        assert_eq!(AssignOp::from_str(">>="), Some(AssignOp::SHR));
+
+        assert_eq!(AssignOp::from_str("PLUS_ASSIGN"), Some(AssignOp::PLUS));
+        assert_eq!(AssignOp::from_str("SHR_ASSIGN"), Some(AssignOp::SHR));
+
+        assert_eq!(AssignOp::from_str("="), None);
+        assert_eq!(AssignOp::from_str("INVALID"), None);
+    }
+}
@@ -14,6 +14,22 @@ pub enum Keyword {
     END,       // Represents the "end" keyword
     TRUE,      // Represents the "true" keyword
     FALSE,     // Represents the "false" keyword
+    MUT,       // Represents the "mut" keyword
+    IN,        // Represents the "in" keyword
+    BREAK,     // Represents the "break" keyword
+    CONTINUE,  // Represents the "continue" keyword
+    LOOP,      // Represents the "loop" keyword
+    MATCH,     // Represents the "match" keyword
+    STRUCT,    // Represents the "struct" keyword
+    ENUM,      // Represents the "enum" keyword
+    IMPL,      // Represents the "impl" keyword
+    TRAIT,     // Represents the "trait" keyword
+    PUB,       // Represents the "pub" keyword
+    USE,       // Represents the "use" keyword
+    MODULE,    // Represents the "mod" keyword
+    AS,        // Represents the "as" keyword
+    SELF,      // Represents the "self" keyword
+    NULL,      // Represents the "null" or "nil" keyword
     UNDEFINED, // Represents an undefined or unrecognized keyword
 }
 
@@ -35,6 +51,22 @@ impl Keyword {
             Keyword::END => "END",
             Keyword::TRUE => "TRUE",
             Keyword::FALSE => "FALSE",
+            Keyword::MUT => "MUT",
+            Keyword::IN => "IN",
+            Keyword::BREAK => "BREAK",
+            Keyword::CONTINUE => "CONTINUE",
+            Keyword::LOOP => "LOOP",
+            Keyword::MATCH => "MATCH",
+            Keyword::STRUCT => "STRUCT",
+            Keyword::ENUM => "ENUM",
+            Keyword::IMPL => "IMPL",
+            Keyword::TRAIT => "TRAIT",
+            Keyword::PUB => "PUB",
+            Keyword::USE => "USE",
+            Keyword::MODULE => "MODULE",
+            Keyword::AS => "AS",
+            Keyword::SELF => "SELF",
+            Keyword::NULL => "NULL",
             _ => "IDENT",
         }
     }
@@ -61,6 +93,22 @@ impl Keyword {
             "end" => Some(Keyword::END),
             "true" => Some(Keyword::TRUE),
             "false" => Some(Keyword::FALSE),
+            "mut" => Some(Keyword::MUT),
+            "in" => Some(Keyword::IN),
+            "break" => Some(Keyword::BREAK),
+            "continue" => Some(Keyword::CONTINUE),
+            "loop" => Some(Keyword::LOOP),
+            "match" => Some(Keyword::MATCH),
+            "struct" => Some(Keyword::STRUCT),
+            "enum" => Some(Keyword::ENUM),
+            "impl" => Some(Keyword::IMPL),
+            "trait" => Some(Keyword::TRAIT),
+            "pub" => Some(Keyword::PUB),
+            "use" => Some(Keyword::USE),
+            "mod" => Some(Keyword::MODULE),
+            "as" => Some(Keyword::AS),
+            "self" => Some(Keyword::SELF),
+            "null" | "nil" => Some(Keyword::NULL),
             _ => None,
         }
     }
@@ -80,6 +128,10 @@ mod tests {
         assert_eq!(Keyword::FOR.as_str(), "FOR");
         assert_eq!(Keyword::WHILE.as_str(), "WHILE");
         assert_eq!(Keyword::FUNCTION.as_str(), "FUNCTION");
+        assert_eq!(Keyword::MUT.as_str(), "MUT");
+        assert_eq!(Keyword::MATCH.as_str(), "MATCH");
+        assert_eq!(Keyword::PUB.as_str(), "PUB");
+        assert_eq!(Keyword::NULL.as_str(), "NULL");
         assert_eq!(Keyword::UNDEFINED.as_str(), "IDENT");
     }
 
@@ -93,6 +145,9 @@ mod tests {
         assert_eq!(Keyword::FOR.to_string(), "FOR");
         assert_eq!(Keyword::WHILE.to_string(), "WHILE");
         assert_eq!(Keyword::FUNCTION.to_string(), "FUNCTION");
+        assert_eq!(Keyword::BREAK.to_string(), "BREAK");
+        assert_eq!(Keyword::ENUM.to_string(), "ENUM");
+        assert_eq!(Keyword::MODULE.to_string(), "MODULE");
         assert_eq!(Keyword::UNDEFINED.to_string(), "IDENT");
     }
 
@@ -112,6 +167,23 @@ mod tests {
         assert_eq!(Keyword::from_str("end"), Some(Keyword::END));
         assert_eq!(Keyword::from_str("true"), Some(Keyword::TRUE));
         assert_eq!(Keyword::from_str("false"), Some(Keyword::FALSE));
+        assert_eq!(Keyword::from_str("mut"), Some(Keyword::MUT));
+        assert_eq!(Keyword::from_str("in"), Some(Keyword::IN));
+        assert_eq!(Keyword::from_str("break"), Some(Keyword::BREAK));
+        assert_eq!(Keyword::from_str("continue"), Some(Keyword::CONTINUE));
+        assert_eq!(Keyword::from_str("loop"), Some(Keyword::LOOP));
+        assert_eq!(Keyword::from_str("match"), Some(Keyword::MATCH));
+        assert_eq!(Keyword::from_str("struct"), Some(Keyword::STRUCT));
+        assert_eq!(Keyword::from_str("enum"), Some(Keyword::ENUM));
+        assert_eq!(Keyword::from_str("impl"), Some(Keyword::IMPL));
+        assert_eq!(Keyword::from_str("trait"), Some(Keyword::TRAIT));
+        assert_eq!(Keyword::from_str("pub"), Some(Keyword::PUB));
+        assert_eq!(Keyword::from_str("use"), Some(Keyword::USE));
+        assert_eq!(Keyword::from_str("mod"), Some(Keyword::MODULE));
+        assert_eq!(Keyword::from_str("as"), Some(Keyword::AS));
+        assert_eq!(Keyword::from_str("self"), Some(Keyword::SELF));
+        assert_eq!(Keyword::from_str("null"), Some(Keyword::NULL));
+        assert_eq!(Keyword::from_str("nil"), Some(Keyword::NULL));
         assert_eq!(Keyword::from_str("undefined"), None);
     }
 }
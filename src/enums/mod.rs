@@ -1,6 +1,17 @@
+// Token enums keep the SCREAMING variant names and the inherent
+// `as_str`/`to_string`/`from_str` helpers used throughout the lexer.
+#![allow(
+    clippy::upper_case_acronyms,
+    clippy::inherent_to_string,
+    clippy::inherent_to_string_shadow_display,
+    clippy::wrong_self_convention
+)]
+
 pub mod arithmetic;
+pub mod assign_op;
 pub mod bitop;
 pub mod cmp;
 pub mod keyword;
 pub mod logicop;
+pub mod number_suffix;
 pub mod token_type;
@@ -0,0 +1,168 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NumberSuffix {
+    I8,    // 8-bit signed integer (i8)
+    I16,   // 16-bit signed integer (i16)
+    I32,   // 32-bit signed integer (i32)
+    I64,   // 64-bit signed integer (i64)
+    I128,  // 128-bit signed integer (i128)
+    ISIZE, // Pointer-sized signed integer (isize)
+    U8,    // 8-bit unsigned integer (u8)
+    U16,   // 16-bit unsigned integer (u16)
+    U32,   // 32-bit unsigned integer (u32)
+    U64,   // 64-bit unsigned integer (u64)
+    U128,  // 128-bit unsigned integer (u128)
+    USIZE, // Pointer-sized unsigned integer (usize)
+    F32,   // 32-bit floating point (f32)
+    F64,   // 64-bit floating point (f64)
+}
+
+impl NumberSuffix {
+    /// Returns the string representation of the NumberSuffix variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberSuffix::I8 => "I8",
+            NumberSuffix::I16 => "I16",
+            NumberSuffix::I32 => "I32",
+            NumberSuffix::I64 => "I64",
+            NumberSuffix::I128 => "I128",
+            NumberSuffix::ISIZE => "ISIZE",
+            NumberSuffix::U8 => "U8",
+            NumberSuffix::U16 => "U16",
+            NumberSuffix::U32 => "U32",
+            NumberSuffix::U64 => "U64",
+            NumberSuffix::U128 => "U128",
+            NumberSuffix::USIZE => "USIZE",
+            NumberSuffix::F32 => "F32",
+            NumberSuffix::F64 => "F64",
+        }
+    }
+
+    /// Converts the NumberSuffix variant to a String.
+    pub fn to_string(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    /// Creates a NumberSuffix variant from a string representation.
+    pub fn from_str(value: &str) -> Option<NumberSuffix> {
+        match value {
+            "I8" | "i8" => Some(NumberSuffix::I8),
+            "I16" | "i16" => Some(NumberSuffix::I16),
+            "I32" | "i32" => Some(NumberSuffix::I32),
+            "I64" | "i64" => Some(NumberSuffix::I64),
+            "I128" | "i128" => Some(NumberSuffix::I128),
+            "ISIZE" | "isize" => Some(NumberSuffix::ISIZE),
+            "U8" | "u8" => Some(NumberSuffix::U8),
+            "U16" | "u16" => Some(NumberSuffix::U16),
+            "U32" | "u32" => Some(NumberSuffix::U32),
+            "U64" | "u64" => Some(NumberSuffix::U64),
+            "U128" | "u128" => Some(NumberSuffix::U128),
+            "USIZE" | "usize" => Some(NumberSuffix::USIZE),
+            "F32" | "f32" => Some(NumberSuffix::F32),
+            "F64" | "f64" => Some(NumberSuffix::F64),
+            _ => None,
+        }
+    }
+
+    /// Returns true for the floating point suffixes (f32, f64).
+    pub fn is_float(&self) -> bool {
+        matches!(self, NumberSuffix::F32 | NumberSuffix::F64)
+    }
+
+    /// Returns the width of the type in bits, pointer-sized types being 64 bits wide.
+    pub fn bits(&self) -> u32 {
+        match self {
+            NumberSuffix::I8 | NumberSuffix::U8 => 8,
+            NumberSuffix::I16 | NumberSuffix::U16 => 16,
+            NumberSuffix::I32 | NumberSuffix::U32 | NumberSuffix::F32 => 32,
+            NumberSuffix::I64 | NumberSuffix::U64 | NumberSuffix::F64 => 64,
+            NumberSuffix::ISIZE | NumberSuffix::USIZE => 64,
+            NumberSuffix::I128 | NumberSuffix::U128 => 128,
+        }
+    }
+
+    /// Returns true for the signed integer and floating point suffixes.
+    pub fn is_signed(&self) -> bool {
+        !matches!(
+            self,
+            NumberSuffix::U8
+                | NumberSuffix::U16
+                | NumberSuffix::U32
+                | NumberSuffix::U64
+                | NumberSuffix::U128
+                | NumberSuffix::USIZE
+        )
+    }
+
+    /// Returns the largest integer literal accepted for this type. Signed types accept one
+    /// more than their maximum so that `-128i8` can be written as a negated literal.
+    pub fn max_literal(&self) -> u128 {
+        match (self.is_signed(), self.bits()) {
+            (true, bits) => 1 << (bits - 1),
+            (false, 128) => u128::MAX,
+            (false, bits) => (1 << bits) - 1,
+        }
+    }
+
+    /// Splits a numeric literal such as "10u8" or "2.5f32" into its digits and its suffix.
+    pub fn split(literal: &str) -> (&str, Option<NumberSuffix>) {
+        match literal.find(['i', 'u', 'f']) {
+            Some(index) => {
+                let (digits, suffix) = literal.split_at(index);
+                (digits, NumberSuffix::from_str(suffix))
+            }
+            None => (literal, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(NumberSuffix::I8.as_str(), "I8");
+        assert_eq!(NumberSuffix::I128.as_str(), "I128");
+        assert_eq!(NumberSuffix::USIZE.as_str(), "USIZE");
+        assert_eq!(NumberSuffix::F32.as_str(), "F32");
+        assert_eq!(NumberSuffix::F64.as_str(), "F64");
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(NumberSuffix::from_str("i8"), Some(NumberSuffix::I8));
+        assert_eq!(NumberSuffix::from_str("i32"), Some(NumberSuffix::I32));
+        assert_eq!(NumberSuffix::from_str("isize"), Some(NumberSuffix::ISIZE));
+        assert_eq!(NumberSuffix::from_str("u8"), Some(NumberSuffix::U8));
+        assert_eq!(NumberSuffix::from_str("u64"), Some(NumberSuffix::U64));
+        assert_eq!(NumberSuffix::from_str("f32"), Some(NumberSuffix::F32));
+        assert_eq!(NumberSuffix::from_str("F64"), Some(NumberSuffix::F64));
+
+        assert_eq!(NumberSuffix::from_str("u7"), None);
+        assert_eq!(NumberSuffix::from_str("INVALID"), None);
+    }
+
+    #[test]
+    fn test_max_literal() {
+        assert_eq!(NumberSuffix::I8.max_literal(), 128);
+        assert_eq!(NumberSuffix::U8.max_literal(), 255);
+        assert_eq!(NumberSuffix::I32.max_literal(), 2_147_483_648);
+        assert_eq!(NumberSuffix::U64.max_literal(), u64::MAX as u128);
+        assert_eq!(NumberSuffix::U128.max_literal(), u128::MAX);
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(NumberSuffix::split("10"), ("10", None));
+        assert_eq!(NumberSuffix::split("10u8"), ("10", Some(NumberSuffix::U8)));
+        assert_eq!(
+            NumberSuffix::split("2.5f32"),
+            ("2.5", Some(NumberSuffix::F32))
+        );
+        assert_eq!(
+            NumberSuffix::split("1e-9f64"),
+            ("1e-9", Some(NumberSuffix::F64))
+        );
+        assert_eq!(NumberSuffix::split("1e-9"), ("1e-9", None));
+    }
+}
@@ -3,8 +3,12 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use super::{arithmetic::Arithmetic, bitop::Bitop, cmp::Cmp, keyword::Keyword, logicop::LogicOp};
+use super::{
+    arithmetic::Arithmetic, assign_op::AssignOp, bitop::Bitop, cmp::Cmp, keyword::Keyword,
+    logicop::LogicOp,
+};
 
+#[allow(non_camel_case_types)]
 #[derive(Default, Copy, Debug, Clone, PartialEq, Eq)]
 pub enum TokenType {
     #[default]
@@ -12,6 +16,13 @@ pub enum TokenType {
     EOF,                    // Represents the end of file marker
     IDENT,                  // Represents an identifier
     INT,                    // Represents an integer
+    FLOAT,                  // Represents a floating point number
+    STRING,                 // Represents a string literal ("...")
+    CHAR,                   // Represents a character literal ('.')
+    DOC_COMMENT,            // Represents a doc comment (/// or //!)
+    TEMPLATE_STRING,        // Represents the text of a template string (`...`)
+    INTERP_START,           // Represents the start of an interpolation (${)
+    INTERP_END,             // Represents the end of an interpolation (})
     ASSIGN,                 // Represents the assignment operator (=)
     DOT,                    // Represents a dot (.)
     COMMA,                  // Represents a comma (,)
@@ -27,6 +38,16 @@ pub enum TokenType {
     RANGE,                  // Represents a right brace (..)
     IRANGE,                 // Represents a right brace (..=)
     SCOPE,                  // Represents a right brace (::)
+    ARROW,                  // Represents a return type arrow (->)
+    FATARROW,               // Represents a match arm arrow (=>)
+    LBRACKET,               // Represents a left square bracket ([)
+    RBRACKET,               // Represents a right square bracket (])
+    QUESTION,               // Represents the error propagation operator (?)
+    AT,                     // Represents an at sign (@)
+    HASH,                   // Represents a hash sign, starting attributes (#)
+    DOLLAR,                 // Represents a dollar sign ($)
+    BANG,                   // Represents the bang of a macro invocation (!)
+    ASSIGNOP(AssignOp),     // Represents compound assignment operators
     CMP(Cmp),               // Represents comparison operators
     ARITHMETIC(Arithmetic), // Represents arithmetic operators
     BITOP(Bitop),           // Represents bitwise operators
@@ -48,6 +69,13 @@ impl TokenType {
             TokenType::EOF => "EOF",
             TokenType::IDENT => "IDENT",
             TokenType::INT => "INT",
+            TokenType::FLOAT => "FLOAT",
+            TokenType::STRING => "STRING",
+            TokenType::CHAR => "CHAR",
+            TokenType::DOC_COMMENT => "DOC_COMMENT",
+            TokenType::TEMPLATE_STRING => "TEMPLATE_STRING",
+            TokenType::INTERP_START => "INTERP_START",
+            TokenType::INTERP_END => "INTERP_END",
             TokenType::ASSIGN => "ASSIGN",
             TokenType::DOT => "DOT",
             TokenType::COMMA => "COMMA",
@@ -63,6 +91,16 @@ impl TokenType {
             TokenType::RANGE => "RANGE",
             TokenType::IRANGE => "IRANGE",
             TokenType::SCOPE => "SCOPE",
+            TokenType::ARROW => "ARROW",
+            TokenType::FATARROW => "FATARROW",
+            TokenType::LBRACKET => "LBRACKET",
+            TokenType::RBRACKET => "RBRACKET",
+            TokenType::QUESTION => "QUESTION",
+            TokenType::AT => "AT",
+            TokenType::HASH => "HASH",
+            TokenType::DOLLAR => "DOLLAR",
+            TokenType::BANG => "BANG",
+            TokenType::ASSIGNOP(a) => a.as_str(),
             TokenType::CMP(c) => c.as_str(),
             TokenType::ARITHMETIC(a) => a.as_str(),
             TokenType::BITOP(b) => b.as_str(),
@@ -83,6 +121,13 @@ impl TokenType {
             "EOF" => Some(TokenType::EOF),
             "IDENT" => Some(TokenType::IDENT),
             "INT" => Some(TokenType::INT),
+            "FLOAT" => Some(TokenType::FLOAT),
+            "STRING" => Some(TokenType::STRING),
+            "CHAR" => Some(TokenType::CHAR),
+            "DOC_COMMENT" => Some(TokenType::DOC_COMMENT),
+            "TEMPLATE_STRING" => Some(TokenType::TEMPLATE_STRING),
+            "INTERP_START" => Some(TokenType::INTERP_START),
+            "INTERP_END" => Some(TokenType::INTERP_END),
             "ASSIGN" => Some(TokenType::ASSIGN),
             "DOT" => Some(TokenType::DOT),
             "COMMA" => Some(TokenType::COMMA),
@@ -98,6 +143,15 @@ impl TokenType {
             "RANGE" => Some(TokenType::RANGE),
             "IRANGE" => Some(TokenType::IRANGE),
             "SCOPE" => Some(TokenType::SCOPE),
+            "ARROW" => Some(TokenType::ARROW),
+            "FATARROW" => Some(TokenType::FATARROW),
+            "LBRACKET" => Some(TokenType::LBRACKET),
+            "RBRACKET" => Some(TokenType::RBRACKET),
+            "QUESTION" => Some(TokenType::QUESTION),
+            "AT" => Some(TokenType::AT),
+            "HASH" => Some(TokenType::HASH),
+            "DOLLAR" => Some(TokenType::DOLLAR),
+            "BANG" => Some(TokenType::BANG),
             _ => {
                 if let Some(cmp) = Cmp::from_str(value) {
                     Some(TokenType::CMP(cmp))
@@ -107,10 +161,10 @@ impl TokenType {
                     Some(TokenType::BITOP(bitop))
                 } else if let Some(logicop) = LogicOp::from_str(value) {
                     Some(TokenType::LOGICOP(logicop))
-                } else if let Some(keyword) = Keyword::from_str(value) {
-                    Some(TokenType::KEYWORD(keyword))
+                } else if let Some(assign_op) = AssignOp::from_str(value) {
+                    Some(TokenType::ASSIGNOP(assign_op))
                 } else {
-                    None
+                    Keyword::from_str(value).map(TokenType::KEYWORD)
                 }
             }
         }
@@ -133,6 +187,13 @@ mod tests {
         assert_eq!(TokenType::EOF.as_str(), "EOF");
         assert_eq!(TokenType::IDENT.as_str(), "IDENT");
         assert_eq!(TokenType::INT.as_str(), "INT");
+        assert_eq!(TokenType::FLOAT.as_str(), "FLOAT");
+        assert_eq!(TokenType::STRING.as_str(), "STRING");
+        assert_eq!(TokenType::CHAR.as_str(), "CHAR");
+        assert_eq!(TokenType::DOC_COMMENT.as_str(), "DOC_COMMENT");
+        assert_eq!(TokenType::TEMPLATE_STRING.as_str(), "TEMPLATE_STRING");
+        assert_eq!(TokenType::INTERP_START.as_str(), "INTERP_START");
+        assert_eq!(TokenType::INTERP_END.as_str(), "INTERP_END");
         assert_eq!(TokenType::ASSIGN.as_str(), "ASSIGN");
         assert_eq!(TokenType::DOT.as_str(), "DOT");
         assert_eq!(TokenType::COMMA.as_str(), "COMMA");
@@ -148,6 +209,16 @@ mod tests {
         assert_eq!(TokenType::RANGE.as_str(), "RANGE");
         assert_eq!(TokenType::IRANGE.as_str(), "IRANGE");
         assert_eq!(TokenType::SCOPE.as_str(), "SCOPE");
+        assert_eq!(TokenType::ARROW.as_str(), "ARROW");
+        assert_eq!(TokenType::FATARROW.as_str(), "FATARROW");
+        assert_eq!(TokenType::LBRACKET.as_str(), "LBRACKET");
+        assert_eq!(TokenType::RBRACKET.as_str(), "RBRACKET");
+        assert_eq!(TokenType::QUESTION.as_str(), "QUESTION");
+        assert_eq!(TokenType::AT.as_str(), "AT");
+        assert_eq!(TokenType::HASH.as_str(), "HASH");
+        assert_eq!(TokenType::DOLLAR.as_str(), "DOLLAR");
+        assert_eq!(TokenType::BANG.as_str(), "BANG");
+        assert_eq!(TokenType::ASSIGNOP(AssignOp::PLUS).as_str(), "PLUS_ASSIGN");
     }
 
     #[test]
@@ -156,6 +227,13 @@ mod tests {
         assert_eq!(TokenType::EOF.to_string(), "EOF");
         assert_eq!(TokenType::IDENT.to_string(), "IDENT");
         assert_eq!(TokenType::INT.to_string(), "INT");
+        assert_eq!(TokenType::FLOAT.to_string(), "FLOAT");
+        assert_eq!(TokenType::STRING.to_string(), "STRING");
+        assert_eq!(TokenType::CHAR.to_string(), "CHAR");
+        assert_eq!(TokenType::DOC_COMMENT.to_string(), "DOC_COMMENT");
+        assert_eq!(TokenType::TEMPLATE_STRING.to_string(), "TEMPLATE_STRING");
+        assert_eq!(TokenType::INTERP_START.to_string(), "INTERP_START");
+        assert_eq!(TokenType::INTERP_END.to_string(), "INTERP_END");
         assert_eq!(TokenType::ASSIGN.to_string(), "ASSIGN");
         assert_eq!(TokenType::DOT.to_string(), "DOT");
         assert_eq!(TokenType::COMMA.to_string(), "COMMA");
@@ -171,6 +249,15 @@ mod tests {
         assert_eq!(TokenType::RANGE.to_string(), "RANGE");
         assert_eq!(TokenType::IRANGE.to_string(), "IRANGE");
         assert_eq!(TokenType::SCOPE.to_string(), "SCOPE");
+        assert_eq!(TokenType::ARROW.to_string(), "ARROW");
+        assert_eq!(TokenType::FATARROW.to_string(), "FATARROW");
+        assert_eq!(TokenType::LBRACKET.to_string(), "LBRACKET");
+        assert_eq!(TokenType::RBRACKET.to_string(), "RBRACKET");
+        assert_eq!(TokenType::QUESTION.to_string(), "QUESTION");
+        assert_eq!(TokenType::AT.to_string(), "AT");
+        assert_eq!(TokenType::HASH.to_string(), "HASH");
+        assert_eq!(TokenType::DOLLAR.to_string(), "DOLLAR");
+        assert_eq!(TokenType::BANG.to_string(), "BANG");
     }
 
     #[test]
@@ -179,6 +266,25 @@ mod tests {
         assert_eq!(TokenType::from_str("EOF"), Some(TokenType::EOF));
         assert_eq!(TokenType::from_str("IDENT"), Some(TokenType::IDENT));
         assert_eq!(TokenType::from_str("INT"), Some(TokenType::INT));
+        assert_eq!(TokenType::from_str("FLOAT"), Some(TokenType::FLOAT));
+        assert_eq!(TokenType::from_str("STRING"), Some(TokenType::STRING));
+        assert_eq!(TokenType::from_str("CHAR"), Some(TokenType::CHAR));
+        assert_eq!(
+            TokenType::from_str("DOC_COMMENT"),
+            Some(TokenType::DOC_COMMENT)
+        );
+        assert_eq!(
+            TokenType::from_str("TEMPLATE_STRING"),
+            Some(TokenType::TEMPLATE_STRING)
+        );
+        assert_eq!(
+            TokenType::from_str("INTERP_START"),
+            Some(TokenType::INTERP_START)
+        );
+        assert_eq!(
+            TokenType::from_str("INTERP_END"),
+            Some(TokenType::INTERP_END)
+        );
         assert_eq!(TokenType::from_str("ASSIGN"), Some(TokenType::ASSIGN));
         assert_eq!(TokenType::from_str("DOT"), Some(TokenType::DOT));
         assert_eq!(TokenType::from_str("COMMA"), Some(TokenType::COMMA));
@@ -194,5 +300,18 @@ mod tests {
         assert_eq!(TokenType::from_str("RANGE"), Some(TokenType::RANGE));
         assert_eq!(TokenType::from_str("IRANGE"), Some(TokenType::IRANGE));
         assert_eq!(TokenType::from_str("SCOPE"), Some(TokenType::SCOPE));
+        assert_eq!(TokenType::from_str("ARROW"), Some(TokenType::ARROW));
+        assert_eq!(TokenType::from_str("FATARROW"), Some(TokenType::FATARROW));
+        assert_eq!(TokenType::from_str("LBRACKET"), Some(TokenType::LBRACKET));
+        assert_eq!(TokenType::from_str("RBRACKET"), Some(TokenType::RBRACKET));
+        assert_eq!(TokenType::from_str("QUESTION"), Some(TokenType::QUESTION));
+        assert_eq!(TokenType::from_str("AT"), Some(TokenType::AT));
+        assert_eq!(TokenType::from_str("HASH"), Some(TokenType::HASH));
+        assert_eq!(TokenType::from_str("DOLLAR"), Some(TokenType::DOLLAR));
+        assert_eq!(TokenType::from_str("BANG"), Some(TokenType::BANG));
+        assert_eq!(
+            TokenType::from_str("<<="),
+            Some(TokenType::ASSIGNOP(AssignOp::SHL))
+        );
     }
 }
@@ -0,0 +1,151 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::{BuildHasherDefault, Hasher},
+    sync::{Mutex, OnceLock},
+};
+
+/// A `HashMap` keyed by names, hashed with `NameHasher`.
+pub type NameMap<K, V> = HashMap<K, V, BuildHasherDefault<NameHasher>>;
+
+/// The Fx hash used by rustc: much faster than the default SipHash on short keys such as
+/// identifiers, at the cost of resistance to crafted collisions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NameHasher(u64);
+
+impl NameHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+
+impl Hasher for NameHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for byte in chunks.remainder() {
+            self.add(*byte as u64);
+        }
+    }
+
+    fn write_u8(&mut self, byte: u8) {
+        self.add(byte as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A handle to an interned string. Symbols of equal strings are equal, so names can be compared
+/// and hashed as integers instead of strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the symbol of `text`, interning it on first use.
+    pub fn intern(text: &str) -> Symbol {
+        Interner::global().lock().unwrap().intern(text)
+    }
+
+    /// Returns the symbol of `text` if it has been interned.
+    pub fn get(text: &str) -> Option<Symbol> {
+        Interner::global().lock().unwrap().get(text)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        Interner::global().lock().unwrap().resolve(self)
+    }
+
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Storage behind `Symbol`. Interned strings live for the rest of the program, which is what
+/// lets `Symbol::as_str` hand out `&'static str` without holding the lock.
+#[derive(Debug, Default)]
+pub struct Interner {
+    symbols: NameMap<&'static str, Symbol>,
+    strings: Vec<&'static str>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn global() -> &'static Mutex<Interner> {
+        static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+        INTERNER.get_or_init(|| Mutex::new(Interner::new()))
+    }
+
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(text) {
+            return *symbol;
+        }
+
+        let symbol = Symbol(self.strings.len() as u32);
+        let text: &'static str = Box::leak(text.into());
+        self.strings.push(text);
+        self.symbols.insert(text, symbol);
+
+        symbol
+    }
+
+    pub fn get(&self, text: &str) -> Option<Symbol> {
+        self.symbols.get(text).copied()
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &'static str {
+        self.strings[symbol.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = Interner::new();
+
+        let x = interner.intern("x");
+        let y = interner.intern("y");
+
+        assert_ne!(x, y);
+        assert_eq!(interner.intern("x"), x);
+        assert_eq!(interner.resolve(x), "x");
+        assert_eq!(interner.resolve(y), "y");
+        assert_eq!(interner.get("z"), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn test_global_symbols() {
+        let symbol = Symbol::intern("été");
+
+        assert_eq!(Symbol::intern("été"), symbol);
+        assert_eq!(Symbol::get("été"), Some(symbol));
+        assert_eq!(symbol.as_str(), "été");
+        assert_eq!(symbol.to_string(), "été");
+    }
+}
@@ -0,0 +1,651 @@
+//! The `Vec<char>` lexer as it was before it borrowed its input, kept so the benchmark can
+//! compare against it on the same source. Not used outside `lexer_bench`.
+
+use crate::{
+    enums::{
+        arithmetic::Arithmetic, bitop::Bitop, cmp::Cmp, keyword::Keyword, logicop::LogicOp,
+        number_suffix::NumberSuffix, token_type::TokenType,
+    },
+    lexer::error::LexError,
+    span::{FileId, Location, Span},
+    token::Token,
+};
+
+#[derive(Default, Debug)]
+pub struct Lexer {
+    input: Vec<char>,
+    position: usize,
+    next_position: usize,
+    c: char,
+    line: usize,
+    column: usize,
+    offset: usize,
+    file_id: FileId,
+    errors: Vec<LexError>,
+}
+
+impl Lexer {
+    pub fn new(input: Vec<char>) -> Lexer {
+        let mut new_lexer = Lexer {
+            input: input.to_owned(),
+            position: 0,
+            next_position: 0,
+            c: '\0',
+            line: 1,
+            column: 1,
+            offset: 0,
+            file_id: FileId::default(),
+            errors: Vec::new(),
+        };
+
+        new_lexer.read_char();
+
+        new_lexer
+    }
+
+    pub fn next_token(&mut self) -> Token {
+        self.maybe_read_whitespace();
+
+        let start = self.mark();
+        let mut token = self.read_token();
+        token.span = self.span_from(start);
+
+        token
+    }
+
+    fn read_token(&mut self) -> Token {
+        let mut new_token: Token = Token {
+            t: TokenType::KEYWORD(Keyword::UNDEFINED),
+            value: self.c.to_string(),
+            span: Span::default(),
+            symbol: None,
+            trivia: None,
+        };
+
+        match self.c {
+            '=' => {
+                if self.peek_next_char() == '=' {
+                    self.read_char(); // Consume '='
+                    new_token.value = "==".to_owned();
+                    new_token.t = TokenType::CMP(Cmp::EQUAL);
+                } else {
+                    new_token.t = TokenType::ASSIGN;
+                }
+            }
+            ';' => new_token.t = TokenType::SEMICOLON,
+            ':' => {
+                if self.peek_next_char() == ':' {
+                    self.read_char();
+                    new_token.value.push(':');
+                    new_token.t = TokenType::SCOPE;
+                } else {
+                    new_token.t = TokenType::COLON;
+                }
+            }
+            '(' => new_token.t = TokenType::LPAREN,
+            ')' => new_token.t = TokenType::RPAREN,
+            '.' => {
+                if self.peek_next_char() == '.' {
+                    self.read_char();
+
+                    new_token.value = "..".to_owned();
+                    if self.peek_next_char() == '=' {
+                        self.read_char();
+
+                        new_token.value.push('=');
+                        new_token.t = TokenType::IRANGE;
+                    } else {
+                        new_token.t = TokenType::RANGE;
+                    }
+                } else {
+                    new_token.t = TokenType::DOT;
+                }
+            }
+            ',' => new_token.t = TokenType::COMMA,
+            '/' if self.is_doc_comment() => {
+                new_token.t = TokenType::DOC_COMMENT;
+                new_token.value = self.read_line_comment();
+
+                return new_token;
+            }
+            '+' | '-' | '*' | '/' | '%' => {
+                if let Some(arithmetic) = Arithmetic::from_str(self.c.to_string().as_str()) {
+                    if self.peek_next_char() == self.c {
+                        let double = format!("{}{}", self.c, self.c);
+                        if let Some(repeated_arithmetic) = Arithmetic::from_str(double.as_str()) {
+                            self.read_char();
+                            new_token.value = double;
+                            new_token.t = TokenType::ARITHMETIC(repeated_arithmetic);
+                        }
+                    } else {
+                        new_token.t = TokenType::ARITHMETIC(arithmetic);
+                    }
+                } else {
+                    new_token.t = TokenType::ILLEGAL;
+                }
+            }
+            '&' | '|' | '~' | '^' => {
+                if let Some(bitop) = Bitop::from_str(self.c.to_string().as_str()) {
+                    if self.peek_next_char() == self.c {
+                        let double = format!("{}{}", self.c, self.c);
+                        if let Some(logicop) = LogicOp::from_str(double.as_str()) {
+                            self.read_char();
+                            new_token.value = double;
+                            new_token.t = TokenType::LOGICOP(logicop);
+                        }
+                    } else {
+                        new_token.t = TokenType::BITOP(bitop);
+                    }
+                } else {
+                    new_token.t = TokenType::ILLEGAL;
+                }
+            }
+            '!' => {
+                if self.peek_next_char() == '=' {
+                    self.read_char(); // Consume '='
+                    new_token.t = TokenType::CMP(Cmp::NEQUAL);
+                } else {
+                    new_token.t = TokenType::LOGICOP(LogicOp::NOT);
+                }
+            }
+            '{' => new_token.t = TokenType::LBRACE,
+            '}' => new_token.t = TokenType::RBRACE,
+            '<' => {
+                if self.peek_next_char() == '=' {
+                    self.read_char(); // Consume '='
+                    new_token.t = TokenType::CMP(Cmp::LE);
+                } else {
+                    new_token.t = TokenType::CMP(Cmp::LT);
+                }
+            }
+            '>' => {
+                if self.peek_next_char() == '=' {
+                    self.read_char(); // Consume '='
+                    new_token.t = TokenType::CMP(Cmp::GE);
+                } else {
+                    new_token.t = TokenType::CMP(Cmp::GT);
+                }
+            }
+            '"' => {
+                new_token.t = TokenType::STRING;
+                new_token.value = self.read_string();
+
+                return new_token;
+            }
+            '\'' => {
+                new_token.t = TokenType::CHAR;
+                new_token.value = self.read_char_literal();
+
+                return new_token;
+            }
+            '`' => new_token.t = TokenType::BACKTICK,
+            '\0' => new_token.t = TokenType::EOF,
+            'a'..='z' | 'A'..='Z' | '_' => {
+                let identifier = self.maybe_read_identifier();
+
+                if let Some(keyword) = Keyword::from_str(&identifier) {
+                    new_token.t = TokenType::KEYWORD(keyword);
+                } else {
+                    new_token.t = TokenType::IDENT;
+                }
+
+                new_token.value = identifier;
+
+                return new_token;
+            }
+            '0'..='9' => {
+                let (t, number) = self.read_number();
+                new_token.t = t;
+                new_token.value = number;
+
+                return new_token;
+            }
+            _ => new_token.t = TokenType::ILLEGAL,
+        };
+
+        self.read_char();
+
+        new_token
+    }
+
+    pub fn read_char(&mut self) -> &Lexer {
+        // Step over the current character, unless it is the placeholder before the first read
+        // or the one past the end of the input.
+        if self.next_position > 0 && !self.is_at_end() {
+            self.offset += self.c.len_utf8();
+
+            if self.c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+
+        if self.next_position >= self.input.len() {
+            self.c = '\0';
+        } else {
+            self.c = self.input[self.next_position];
+        }
+
+        self.position = self.next_position;
+        self.next_position += 1;
+        self
+    }
+
+    pub fn peek_next_char(&self) -> char {
+        if self.next_position >= self.input.len() {
+            '\0'
+        } else {
+            self.input[self.next_position]
+        }
+    }
+
+    fn location(&self) -> Location {
+        Location::new(self.line, self.column)
+    }
+
+    /// Captures the current location and byte offset, to be turned into a span with `span_from`.
+    fn mark(&self) -> (Location, usize) {
+        (self.location(), self.offset)
+    }
+
+    fn span_from(&self, (start, offset): (Location, usize)) -> Span {
+        Span::new(self.file_id, start, self.location(), offset..self.offset)
+    }
+
+    fn peek_nth_char(&self, n: usize) -> char {
+        self.input
+            .get(self.next_position + n)
+            .copied()
+            .unwrap_or('\0')
+    }
+
+    fn maybe_read_identifier(&mut self) -> String {
+        let position = self.position;
+
+        while self.c.is_alphanumeric() || self.c == '_' {
+            self.read_char();
+        }
+
+        self.input[position..self.position]
+            .iter()
+            .collect::<String>()
+    }
+
+    fn read_number(&mut self) -> (TokenType, String) {
+        let start = self.mark();
+        let position = self.position;
+        let mut t = TokenType::INT;
+
+        let radix = match (self.c, self.peek_nth_char(0)) {
+            ('0', 'x') => 16,
+            ('0', 'o') => 8,
+            ('0', 'b') => 2,
+            _ => 10,
+        };
+
+        if radix != 10 {
+            self.read_char(); // Consume '0'
+            self.read_char(); // Consume the radix prefix
+        }
+
+        let digits_position = self.position;
+
+        if radix == 16 {
+            while self.c.is_ascii_hexdigit() || self.c == '_' {
+                self.read_char();
+            }
+        } else {
+            // Binary and octal literals read every decimal digit so that `0b102` reports the '2'.
+            self.read_digits();
+        }
+
+        // Only a '.' followed by a digit starts a fraction, so `0..10` stays a RANGE.
+        if radix == 10 && self.c == '.' && self.peek_nth_char(0).is_ascii_digit() {
+            t = TokenType::FLOAT;
+            self.read_char(); // Consume '.'
+            self.read_digits();
+        }
+
+        if radix == 10 && matches!(self.c, 'e' | 'E') {
+            let next = self.peek_nth_char(0);
+            let signed = matches!(next, '+' | '-') && self.peek_nth_char(1).is_ascii_digit();
+
+            if next.is_ascii_digit() || signed {
+                t = TokenType::FLOAT;
+                self.read_char(); // Consume 'e'
+                if signed {
+                    self.read_char();
+                }
+                self.read_digits();
+            }
+        }
+
+        let suffix_position = self.position;
+        while self.c.is_alphanumeric() || self.c == '_' {
+            self.read_char();
+        }
+
+        let literal = self.input[position..self.position]
+            .iter()
+            .collect::<String>();
+        let digits = self.input[digits_position..suffix_position]
+            .iter()
+            .filter(|c| **c != '_')
+            .collect::<String>();
+        let suffix = self.input[suffix_position..self.position]
+            .iter()
+            .collect::<String>();
+
+        let number_suffix = NumberSuffix::from_str(&suffix);
+        let malformed = match number_suffix {
+            _ if suffix.is_empty() => None,
+            Some(s) if s.is_float() && radix != 10 => {
+                Some(format!("float suffix on a base {} literal", radix))
+            }
+            Some(s) if s.is_float() => {
+                t = TokenType::FLOAT;
+                None
+            }
+            Some(_) if t == TokenType::INT => None,
+            Some(_) => Some("integer suffix on a float literal".to_owned()),
+            None => Some(format!("unknown suffix '{}'", suffix)),
+        };
+
+        let span = self.span_from(start);
+
+        if let Some(reason) = malformed {
+            self.errors
+                .push(LexError::malformed_number(&literal, &reason, span));
+            return (t, literal);
+        }
+
+        if t == TokenType::FLOAT {
+            return (t, literal.replace('_', ""));
+        }
+
+        match Self::check_integer(&literal, &digits, radix, number_suffix, span) {
+            Ok(value) => (t, format!("{}{}", value, suffix)),
+            Err(error) => {
+                self.errors.push(error);
+                (t, literal)
+            }
+        }
+    }
+
+    /// Validates the digits of an integer literal and returns its value, which must fit the
+    /// suffix's width (i32 when there is no suffix).
+    fn check_integer(
+        literal: &str,
+        digits: &str,
+        radix: u32,
+        suffix: Option<NumberSuffix>,
+        span: Span,
+    ) -> Result<u128, LexError> {
+        if digits.is_empty() {
+            return Err(LexError::malformed_number(literal, "missing digits", span));
+        }
+
+        if let Some(digit) = digits.chars().find(|c| !c.is_digit(radix)) {
+            return Err(LexError::invalid_digit(digit, radix, span));
+        }
+
+        let suffix = suffix.unwrap_or(NumberSuffix::I32);
+        let value = u128::from_str_radix(digits, radix)
+            .ok()
+            .filter(|value| *value <= suffix.max_literal())
+            .ok_or_else(|| LexError::integer_overflow(literal, suffix, span))?;
+
+        Ok(value)
+    }
+
+    fn read_digits(&mut self) {
+        while self.c.is_ascii_digit() || self.c == '_' {
+            self.read_char();
+        }
+    }
+
+    fn read_string(&mut self) -> String {
+        let start = self.mark();
+        let mut value = String::new();
+
+        self.read_char(); // Consume the opening '"'
+
+        loop {
+            if self.is_at_end() {
+                self.errors
+                    .push(LexError::unterminated_string(self.span_from(start)));
+                break;
+            }
+
+            match self.c {
+                '"' => {
+                    self.read_char(); // Consume the closing '"'
+                    break;
+                }
+                '\\' => {
+                    if let Some(escaped) = self.read_escape() {
+                        value.push(escaped);
+                    }
+                }
+                c => {
+                    value.push(c);
+                    self.read_char();
+                }
+            }
+        }
+
+        value
+    }
+
+    fn read_char_literal(&mut self) -> String {
+        let start = self.mark();
+        let errors = self.errors.len();
+        let mut value = String::new();
+
+        self.read_char(); // Consume the opening '\''
+
+        while self.c != '\'' {
+            if self.is_at_end() || self.c == '\n' {
+                self.errors
+                    .push(LexError::unterminated_char(self.span_from(start)));
+                return value;
+            }
+
+            if self.c == '\\' {
+                if let Some(escaped) = self.read_escape() {
+                    value.push(escaped);
+                }
+            } else {
+                value.push(self.c);
+                self.read_char();
+            }
+        }
+
+        self.read_char(); // Consume the closing '\''
+
+        // A bad escape has already been reported, don't pile a length error on top of it.
+        if self.errors.len() == errors && value.chars().count() != 1 {
+            self.errors.push(LexError::invalid_char_literal(
+                &value,
+                self.span_from(start),
+            ));
+        }
+
+        value
+    }
+
+    fn read_escape(&mut self) -> Option<char> {
+        let start = self.mark();
+
+        self.read_char(); // Consume '\'
+
+        let escaped = match self.c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'u' => return self.read_unicode_escape(start),
+            // Leave the missing closing quote to the caller.
+            _ if self.is_at_end() => return None,
+            c => {
+                self.errors.push(LexError::invalid_escape(
+                    &format!("\\{}", c),
+                    self.span_from(start),
+                ));
+                self.read_char();
+                return None;
+            }
+        };
+
+        self.read_char();
+
+        Some(escaped)
+    }
+
+    fn read_unicode_escape(&mut self, start: (Location, usize)) -> Option<char> {
+        self.read_char(); // Consume 'u'
+
+        if self.c != '{' {
+            self.errors
+                .push(LexError::invalid_escape("\\u", self.span_from(start)));
+            return None;
+        }
+
+        self.read_char(); // Consume '{'
+
+        let position = self.position;
+        while self.c.is_ascii_hexdigit() {
+            self.read_char();
+        }
+        let digits = self.input[position..self.position]
+            .iter()
+            .collect::<String>();
+
+        if self.c != '}' {
+            self.errors.push(LexError::invalid_escape(
+                &format!("\\u{{{}", digits),
+                self.span_from(start),
+            ));
+            return None;
+        }
+
+        self.read_char(); // Consume '}'
+
+        // At most six hex digits, and the value must be a Unicode scalar (no surrogates).
+        let scalar = u32::from_str_radix(&digits, 16)
+            .ok()
+            .filter(|_| digits.len() <= 6)
+            .and_then(char::from_u32);
+
+        if scalar.is_none() {
+            self.errors.push(LexError::invalid_escape(
+                &format!("\\u{{{}}}", digits),
+                self.span_from(start),
+            ));
+        }
+
+        scalar
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    fn maybe_read_until<F>(&mut self, mut condition: F) -> String
+    where
+        F: FnMut() -> bool,
+    {
+        let position = self.position;
+
+        while condition() {
+            self.read_char();
+        }
+
+        self.input[position..self.position]
+            .iter()
+            .collect::<String>()
+    }
+
+    pub fn errors(&self) -> &[LexError] {
+        self.errors.as_ref()
+    }
+
+    /// Skips whitespace and comments, stopping at doc comments since those become tokens.
+    fn maybe_read_whitespace(&mut self) {
+        loop {
+            match self.c {
+                ' ' | '\t' | '\n' | '\r' => {
+                    self.read_char();
+                }
+                '/' if self.peek_next_char() == '/' && !self.is_doc_comment() => {
+                    self.read_line_comment();
+                }
+                '/' if self.peek_next_char() == '*' => {
+                    self.read_block_comment();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn is_doc_comment(&self) -> bool {
+        // `///` and `//!` are doc comments, `////` is a regular comment again.
+        self.c == '/'
+            && self.peek_nth_char(0) == '/'
+            && match self.peek_nth_char(1) {
+                '!' => true,
+                '/' => self.peek_nth_char(2) != '/',
+                _ => false,
+            }
+    }
+
+    fn read_line_comment(&mut self) -> String {
+        let position = self.position;
+
+        while self.c != '\n' && !self.is_at_end() {
+            self.read_char();
+        }
+
+        self.input[position..self.position]
+            .iter()
+            .collect::<String>()
+            .trim_end_matches('\r')
+            .to_owned()
+    }
+
+    fn read_block_comment(&mut self) {
+        let start = self.mark();
+        let mut depth = 0;
+
+        loop {
+            if self.is_at_end() {
+                self.errors
+                    .push(LexError::unterminated_comment(self.span_from(start)));
+                break;
+            }
+
+            match (self.c, self.peek_next_char()) {
+                ('/', '*') => {
+                    depth += 1;
+                    self.read_char();
+                }
+                ('*', '/') => {
+                    depth -= 1;
+                    self.read_char();
+                }
+                _ => {}
+            }
+
+            self.read_char();
+
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+}
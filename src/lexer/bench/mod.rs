@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod baseline;
+
+#[cfg(test)]
+mod lexer_bench {
+
+    use std::time::{Duration, Instant};
+
+    use super::baseline;
+    use crate::{enums::token_type::TokenType, lexer::Lexer};
+
+    // Avoids compound assignments and macro bangs, which the baseline lexes differently, so
+    // that every lexer produces the same tokens.
+    const SAMPLE: &str = include_str!("../../../data/bench.zpp");
+    const REPEAT: usize = 20_000;
+
+    fn throughput(bytes: usize, elapsed: Duration) -> f64 {
+        bytes as f64 / elapsed.as_secs_f64() / 1_000_000.0
+    }
+
+    /// Run with `cargo test --release -- --ignored --nocapture lexer_bench`.
+    #[test]
+    #[ignore]
+    fn bench_lexer_throughput() {
+        let source = SAMPLE.repeat(REPEAT);
+
+        // The baseline pays for collecting the source into a `Vec<char>`, as its callers did.
+        let start = Instant::now();
+        let mut lexer = baseline::Lexer::new(source.chars().collect());
+        let mut baseline_tokens = 0;
+        while lexer.next_token().t != TokenType::EOF {
+            baseline_tokens += 1;
+        }
+        let chars = start.elapsed();
+
+        let start = Instant::now();
+        let mut lexer = Lexer::new(&source);
+        let mut tokens = 0;
+        while lexer.next_token().t != TokenType::EOF {
+            tokens += 1;
+        }
+        let owned = start.elapsed();
+
+        let start = Instant::now();
+        let mut lexer = Lexer::new(&source);
+        let mut lexemes = 0;
+        while lexer.next_lexeme().t != TokenType::EOF {
+            lexemes += 1;
+        }
+        let borrowed = start.elapsed();
+
+        assert_eq!(baseline_tokens, tokens);
+        assert_eq!(tokens, lexemes);
+
+        println!("lexed {} bytes into {} tokens", source.len(), tokens);
+        println!(
+            "baseline:    {:>8.1?} ({:.1} MB/s)",
+            chars,
+            throughput(source.len(), chars)
+        );
+        println!(
+            "next_token:  {:>8.1?} ({:.1} MB/s)",
+            owned,
+            throughput(source.len(), owned)
+        );
+        println!(
+            "next_lexeme: {:>8.1?} ({:.1} MB/s)",
+            borrowed,
+            throughput(source.len(), borrowed)
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::enums::keyword::Keyword;
+
+/// The surface syntax a lexer accepts: which words are keywords, spelled how, and which
+/// operators exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dialect {
+    pub name: &'static str,
+    pub keywords: &'static [(&'static str, Keyword)],
+    // A '!' right after an identifier invokes a macro, as in `println!(...)`.
+    pub macros: bool,
+    // `+=`, `<<=` and the other compound assignments, otherwise lexed as two operators.
+    pub compound_assignment: bool,
+    // Backtick template strings, otherwise a lone BACKTICK token.
+    pub template_strings: bool,
+}
+
+impl Dialect {
+    /// Every keyword and operator, as `Keyword::from_str` spells them.
+    pub const MIXED: Dialect = Dialect {
+        name: "mixed",
+        keywords: &[
+            ("let", Keyword::LET),
+            ("const", Keyword::CONST),
+            ("var", Keyword::VAR),
+            ("auto", Keyword::AUTO),
+            ("return", Keyword::RETURN),
+            ("for", Keyword::FOR),
+            ("while", Keyword::WHILE),
+            ("fn", Keyword::FUNCTION),
+            ("if", Keyword::IF),
+            ("else", Keyword::ELSE),
+            ("do", Keyword::DO),
+            ("end", Keyword::END),
+            ("true", Keyword::TRUE),
+            ("false", Keyword::FALSE),
+            ("mut", Keyword::MUT),
+            ("in", Keyword::IN),
+            ("break", Keyword::BREAK),
+            ("continue", Keyword::CONTINUE),
+            ("loop", Keyword::LOOP),
+            ("match", Keyword::MATCH),
+            ("struct", Keyword::STRUCT),
+            ("enum", Keyword::ENUM),
+            ("impl", Keyword::IMPL),
+            ("trait", Keyword::TRAIT),
+            ("pub", Keyword::PUB),
+            ("use", Keyword::USE),
+            ("mod", Keyword::MODULE),
+            ("as", Keyword::AS),
+            ("self", Keyword::SELF),
+            ("null", Keyword::NULL),
+            ("nil", Keyword::NULL),
+        ],
+        macros: true,
+        compound_assignment: true,
+        template_strings: true,
+    };
+
+    /// `fn main() { let x = 10; for i in 0..10 { ... } }`, as in data/rust_like.zpp.
+    pub const RUST_LIKE: Dialect = Dialect {
+        name: "Rust-like",
+        keywords: &[
+            ("let", Keyword::LET),
+            ("const", Keyword::CONST),
+            ("return", Keyword::RETURN),
+            ("for", Keyword::FOR),
+            ("while", Keyword::WHILE),
+            ("fn", Keyword::FUNCTION),
+            ("if", Keyword::IF),
+            ("else", Keyword::ELSE),
+            ("true", Keyword::TRUE),
+            ("false", Keyword::FALSE),
+            ("mut", Keyword::MUT),
+            ("in", Keyword::IN),
+            ("break", Keyword::BREAK),
+            ("continue", Keyword::CONTINUE),
+            ("loop", Keyword::LOOP),
+            ("match", Keyword::MATCH),
+            ("struct", Keyword::STRUCT),
+            ("enum", Keyword::ENUM),
+            ("impl", Keyword::IMPL),
+            ("trait", Keyword::TRAIT),
+            ("pub", Keyword::PUB),
+            ("use", Keyword::USE),
+            ("mod", Keyword::MODULE),
+            ("as", Keyword::AS),
+            ("self", Keyword::SELF),
+            ("null", Keyword::NULL),
+        ],
+        macros: true,
+        compound_assignment: true,
+        template_strings: true,
+    };
+
+    /// `let add = fn(x, y) { x + y }`, as in data/add.zpp.
+    pub const MONKEY_LIKE: Dialect = Dialect {
+        name: "Monkey-like",
+        keywords: &[
+            ("let", Keyword::LET),
+            ("return", Keyword::RETURN),
+            ("fn", Keyword::FUNCTION),
+            ("if", Keyword::IF),
+            ("else", Keyword::ELSE),
+            ("true", Keyword::TRUE),
+            ("false", Keyword::FALSE),
+        ],
+        macros: false,
+        compound_assignment: false,
+        template_strings: false,
+    };
+
+    /// `local function add(x, y) return x + y end` and `while x do ... end`.
+    pub const LUA_LIKE: Dialect = Dialect {
+        name: "Lua-like",
+        keywords: &[
+            ("local", Keyword::LET),
+            ("return", Keyword::RETURN),
+            ("for", Keyword::FOR),
+            ("while", Keyword::WHILE),
+            ("function", Keyword::FUNCTION),
+            ("if", Keyword::IF),
+            ("else", Keyword::ELSE),
+            ("do", Keyword::DO),
+            ("end", Keyword::END),
+            ("true", Keyword::TRUE),
+            ("false", Keyword::FALSE),
+            ("in", Keyword::IN),
+            ("break", Keyword::BREAK),
+            ("nil", Keyword::NULL),
+        ],
+        macros: false,
+        compound_assignment: false,
+        template_strings: false,
+    };
+
+    /// Returns the keyword spelled `word` in this dialect.
+    pub fn keyword(&self, word: &str) -> Option<Keyword> {
+        self.keywords
+            .iter()
+            .find(|(spelling, _)| *spelling == word)
+            .map(|(_, keyword)| *keyword)
+    }
+
+    /// Whether `word` is a keyword this dialect leaves out, such as `do` in the Rust-like one.
+    pub fn is_foreign_keyword(&self, word: &str) -> bool {
+        self.keyword(word).is_none() && Keyword::from_str(word).is_some()
+    }
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Dialect::MIXED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyword() {
+        assert_eq!(Dialect::RUST_LIKE.keyword("fn"), Some(Keyword::FUNCTION));
+        assert_eq!(
+            Dialect::LUA_LIKE.keyword("function"),
+            Some(Keyword::FUNCTION)
+        );
+        assert_eq!(Dialect::LUA_LIKE.keyword("local"), Some(Keyword::LET));
+        assert_eq!(Dialect::LUA_LIKE.keyword("fn"), None);
+        assert_eq!(Dialect::MONKEY_LIKE.keyword("while"), None);
+    }
+
+    #[test]
+    fn test_mixed_matches_keyword_from_str() {
+        for (word, keyword) in Dialect::MIXED.keywords {
+            assert_eq!(Keyword::from_str(word), Some(*keyword));
+        }
+        assert!(!Dialect::MIXED.is_foreign_keyword("end"));
+    }
+
+    #[test]
+    fn test_is_foreign_keyword() {
+        assert!(Dialect::RUST_LIKE.is_foreign_keyword("do"));
+        assert!(Dialect::RUST_LIKE.is_foreign_keyword("var"));
+        assert!(Dialect::LUA_LIKE.is_foreign_keyword("let"));
+        assert!(!Dialect::LUA_LIKE.is_foreign_keyword("local"));
+        assert!(!Dialect::RUST_LIKE.is_foreign_keyword("local"));
+    }
+}
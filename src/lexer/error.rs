@@ -0,0 +1,223 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{enums::number_suffix::NumberSuffix, span::Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexErrorCode {
+    UnterminatedString = 2_000,
+    InvalidEscape,
+    MalformedNumber,
+    InvalidDigit,
+    IntegerOverflow,
+    UnterminatedComment,
+    UnterminatedChar,
+    InvalidCharLiteral,
+    ConfusableIdentifier,
+    UnterminatedTemplate,
+    UnknownCharacter,
+    ForeignKeyword,
+}
+
+impl Display for LexErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexError {
+    code: LexErrorCode,
+    message: String,
+    span: Span,
+}
+
+impl LexError {
+    pub fn new(code: LexErrorCode, message: impl Into<String>, span: Span) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            span,
+        }
+    }
+
+    pub fn unknown_character(c: char, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::UnknownCharacter,
+            format!(
+                "Unknown character '{}' (U+{:04X})",
+                c.escape_debug(),
+                c as u32
+            ),
+            span,
+        )
+    }
+
+    pub fn unterminated_string(span: Span) -> Self {
+        Self::new(
+            LexErrorCode::UnterminatedString,
+            "Unterminated string literal",
+            span,
+        )
+    }
+
+    pub fn unterminated_comment(span: Span) -> Self {
+        Self::new(
+            LexErrorCode::UnterminatedComment,
+            "Unterminated block comment",
+            span,
+        )
+    }
+
+    pub fn unterminated_template(span: Span) -> Self {
+        Self::new(
+            LexErrorCode::UnterminatedTemplate,
+            "Unterminated template string",
+            span,
+        )
+    }
+
+    pub fn unterminated_char(span: Span) -> Self {
+        Self::new(
+            LexErrorCode::UnterminatedChar,
+            "Unterminated character literal",
+            span,
+        )
+    }
+
+    pub fn invalid_char_literal(value: &str, span: Span) -> Self {
+        let message = if value.is_empty() {
+            "Empty character literal".to_owned()
+        } else {
+            format!(
+                "Character literal '{}' must contain exactly one character",
+                value
+            )
+        };
+
+        Self::new(LexErrorCode::InvalidCharLiteral, message, span)
+    }
+
+    pub fn invalid_escape(sequence: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::InvalidEscape,
+            format!("Invalid escape sequence: '{}'", sequence),
+            span,
+        )
+    }
+
+    pub fn malformed_number(literal: &str, reason: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::MalformedNumber,
+            format!("Malformed number '{}': {}", literal, reason),
+            span,
+        )
+    }
+
+    pub fn invalid_digit(digit: char, radix: u32, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::InvalidDigit,
+            format!("Invalid digit '{}' in a base {} literal", digit, radix),
+            span,
+        )
+    }
+
+    pub fn integer_overflow(literal: &str, suffix: NumberSuffix, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::IntegerOverflow,
+            format!(
+                "Integer literal '{}' does not fit in {}",
+                literal,
+                suffix.as_str().to_lowercase()
+            ),
+            span,
+        )
+    }
+
+    pub fn foreign_keyword(word: &str, dialect: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::ForeignKeyword,
+            format!("'{}' is not a keyword of the {} dialect", word, dialect),
+            span,
+        )
+    }
+
+    pub fn confusable_identifier(identifier: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorCode::ConfusableIdentifier,
+            format!(
+                "Identifier '{}' mixes scripts with characters that look alike",
+                identifier
+            ),
+            span,
+        )
+    }
+
+    pub fn code(&self) -> &LexErrorCode {
+        &self.code
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub fn line(&self) -> usize {
+        self.span.start.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.span.start.column
+    }
+}
+
+impl Error for LexError {}
+
+impl Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Error({}): {} (line {}, column {})",
+            self.code,
+            self.message,
+            self.line(),
+            self.column()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::{FileId, Location};
+
+    #[test]
+    fn test_lex_error_display() {
+        let span = Span::new(
+            FileId(0),
+            Location::new(3, 42),
+            Location::new(3, 50),
+            60..68,
+        );
+        let error = LexError::unterminated_string(span.clone());
+
+        assert_eq!(error.code(), &LexErrorCode::UnterminatedString);
+        assert_eq!(error.span(), &span);
+        assert_eq!(error.line(), 3);
+        assert_eq!(error.column(), 42);
+        assert_eq!(
+            format!("{}", error),
+            "Error(UnterminatedString): Unterminated string literal (line 3, column 42)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_escape_message() {
+        let error = LexError::invalid_escape("\\q", Span::default());
+
+        assert_eq!(error.code(), &LexErrorCode::InvalidEscape);
+        assert_eq!(error.message(), "Invalid escape sequence: '\\q'");
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+use crate::{enums::token_type::TokenType, span::Location, token::Token};
+
+use super::{Checkpoint, Lexer, TemplateMode};
+
+/// Replaces the bytes in `range` of the previous source with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// How far the bytes after the edit move.
+    fn delta(&self) -> isize {
+        self.replacement.len() as isize - self.range.len() as isize
+    }
+}
+
+impl<'a> Lexer<'a> {
+    /// Updates `tokens`, lexed (without trivia) from the source before `edit`, to match the
+    /// input of this lexer, the source after it. Lexing restarts from the last token before the edit that
+    /// is preceded by whitespace or a comment, since no token looks past those, and stops as
+    /// soon as a token lines up with a previous one in the same lexer state. The tokens after
+    /// it are reused with their spans shifted.
+    pub fn relex(mut self, tokens: &[Token], edit: &TextEdit) -> Vec<Token> {
+        let Some(restart) = Self::restart_index(tokens, edit) else {
+            self.file_id = tokens
+                .first()
+                .map_or_else(Default::default, |t| t.span.file_id);
+            return self.collect();
+        };
+
+        let templates_enabled = self.dialect.template_strings;
+        let replay = |templates: &mut Vec<TemplateMode>, token: &Token| {
+            if templates_enabled {
+                Self::replay_templates(templates, token);
+            }
+        };
+
+        let mut templates = Vec::new();
+        for token in &tokens[..restart] {
+            replay(&mut templates, token);
+        }
+
+        let before = &tokens[restart - 1];
+        let mut lexer = self.resume(
+            0,
+            tokens[restart].span.file_id,
+            Checkpoint {
+                position: tokens[restart].span.byte_range.start,
+                location: tokens[restart].span.start,
+                previous: Some((before.t, before.span.byte_range.end)),
+                templates: templates.clone(),
+            },
+        );
+
+        let mut relexed = tokens[..restart].to_vec();
+        let mut old = restart;
+
+        loop {
+            let token = lexer.next_token();
+            let start = token.span.byte_range.start as isize;
+
+            // Skip the previous tokens that can no longer line up with this one.
+            while old < tokens.len() {
+                let old_start = tokens[old].span.byte_range.start;
+                if old_start >= edit.range.end && old_start as isize + edit.delta() >= start {
+                    break;
+                }
+
+                replay(&mut templates, &tokens[old]);
+                old += 1;
+            }
+
+            if old < tokens.len() {
+                let mut after = templates.clone();
+                replay(&mut after, &tokens[old]);
+
+                let candidate = &tokens[old];
+                let lines_up = candidate.t == token.t
+                    && candidate.value == token.value
+                    && candidate.span.byte_range.start as isize + edit.delta() == start
+                    && candidate.span.byte_range.end as isize + edit.delta()
+                        == token.span.byte_range.end as isize
+                    && Self::same_modes(&after, &lexer.templates);
+
+                if lines_up {
+                    relexed.extend(Self::shift(&tokens[old..], candidate, &token, edit));
+                    return relexed;
+                }
+            }
+
+            let eof = token.t == TokenType::EOF;
+            relexed.push(token);
+
+            if eof {
+                return relexed;
+            }
+        }
+    }
+
+    /// Finds the token to restart from, or `None` to lex everything again.
+    fn restart_index(tokens: &[Token], edit: &TextEdit) -> Option<usize> {
+        // The first token that the edit touches or that could grow into it.
+        let mut index = tokens
+            .iter()
+            .position(|token| token.span.byte_range.end >= edit.range.start)?;
+
+        if tokens[index].span.byte_range.start > edit.range.start {
+            index = index.checked_sub(1)?;
+        }
+
+        while index > 0
+            && tokens[index - 1].span.byte_range.end >= tokens[index].span.byte_range.start
+        {
+            index -= 1;
+        }
+
+        (index > 0).then_some(index)
+    }
+
+    /// Applies `token` to a template stack the way the lexer did when producing it.
+    fn replay_templates(templates: &mut Vec<TemplateMode>, token: &Token) {
+        match token.t {
+            TokenType::BACKTICK => match templates.last() {
+                Some(TemplateMode::Text(..)) => {
+                    templates.pop();
+                }
+                _ => templates.push(TemplateMode::Text(
+                    token.span.start,
+                    token.span.byte_range.start,
+                )),
+            },
+            TokenType::INTERP_START => templates.push(TemplateMode::Interpolation(0)),
+            TokenType::INTERP_END => {
+                templates.pop();
+            }
+            TokenType::LBRACE => {
+                if let Some(TemplateMode::Interpolation(depth)) = templates.last_mut() {
+                    *depth += 1;
+                }
+            }
+            TokenType::RBRACE => {
+                if let Some(TemplateMode::Interpolation(depth)) = templates.last_mut() {
+                    *depth -= 1;
+                }
+            }
+            TokenType::EOF => templates.clear(),
+            _ => {}
+        }
+    }
+
+    /// Compares template stacks, ignoring where the templates were opened.
+    fn same_modes(lhs: &[TemplateMode], rhs: &[TemplateMode]) -> bool {
+        lhs.len() == rhs.len()
+            && lhs.iter().zip(rhs).all(|modes| match modes {
+                (TemplateMode::Text(..), TemplateMode::Text(..)) => true,
+                (lhs, rhs) => lhs == rhs,
+            })
+    }
+
+    /// Moves `tokens` so that `old`, their first token, ends up where `new` is.
+    fn shift(tokens: &[Token], old: &Token, new: &Token, edit: &TextEdit) -> Vec<Token> {
+        let line = old.span.start.line;
+        let lines = new.span.start.line as isize - line as isize;
+        let columns = new.span.start.column as isize - old.span.start.column as isize;
+
+        // Only the rest of the edited line moves sideways.
+        let move_location = |location: Location| {
+            let column = if location.line == line {
+                (location.column as isize + columns) as usize
+            } else {
+                location.column
+            };
+
+            Location::new((location.line as isize + lines) as usize, column)
+        };
+
+        tokens
+            .iter()
+            .map(|token| {
+                let mut token = token.clone();
+                let range = &token.span.byte_range;
+
+                token.span.byte_range = (range.start as isize + edit.delta()) as usize
+                    ..(range.end as isize + edit.delta()) as usize;
+                token.span.start = move_location(token.span.start);
+                token.span.end = move_location(token.span.end);
+
+                token
+            })
+            .collect()
+    }
+}
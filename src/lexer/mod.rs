@@ -1,252 +1,1140 @@
+use std::borrow::Cow;
+
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+use unicode_security::{skeleton, MixedScript};
+use unicode_xid::UnicodeXID;
+
 use crate::{
     enums::{
-        arithmetic::Arithmetic, bitop::Bitop, cmp::Cmp, keyword::Keyword, logicop::LogicOp,
-        token_type::TokenType,
+        arithmetic::Arithmetic, assign_op::AssignOp, bitop::Bitop, cmp::Cmp, logicop::LogicOp,
+        number_suffix::NumberSuffix, token_type::TokenType,
     },
-    token::Token,
+    interner::{NameMap, Symbol},
+    source_map::SourceMap,
+    span::{FileId, Location, Span},
+    token::{Lexeme, Token, TokenTrivia, Trivia, TriviaKind},
 };
 
+use self::{dialect::Dialect, error::LexError};
+
+mod bench;
+pub mod dialect;
+pub mod error;
+pub mod incremental;
+pub mod stream;
 mod test;
 
+/// Lexing context inside template strings, the innermost one being the last pushed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TemplateMode {
+    Text(Location, usize), // Inside the text of a template opened at this location and offset
+    Interpolation(usize),  // Inside `${...}`, counting the braces opened since
+}
+
+/// Where a lexer stopped, so that a lexer over a refilled buffer can carry on from there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Checkpoint {
+    position: usize,
+    location: Location,
+    previous: Option<(TokenType, usize)>,
+    templates: Vec<TemplateMode>,
+}
+
+impl Default for Checkpoint {
+    fn default() -> Self {
+        Self {
+            position: 0,
+            location: Location::new(1, 1),
+            previous: None,
+            templates: Vec::new(),
+        }
+    }
+}
+
+/// Lexer borrowing its input. `position` and `next_position` are byte offsets into `input`,
+/// which itself starts `offset` bytes into the file.
 #[derive(Default, Debug)]
-pub struct Lexer {
-    input: Vec<char>,
+pub struct Lexer<'a> {
+    input: &'a str,
     position: usize,
     next_position: usize,
     c: char,
     line: usize,
+    column: usize,
+    offset: usize,
+    file_id: FileId,
+    errors: Vec<LexError>,
+    warnings: Vec<LexError>,
+    previous: Option<(TokenType, usize)>,
+    templates: Vec<TemplateMode>,
+    // Identifiers already interned, by source text, sparing the lock on `Symbol::intern`.
+    symbols: NameMap<&'a str, Symbol>,
+    dialect: Dialect,
+    keep_trivia: bool,
+    finished: bool,
 }
 
-impl Lexer {
-    pub fn new(input: Vec<char>) -> Lexer {
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Lexer<'a> {
         let mut new_lexer = Lexer {
-            input: input.to_owned(),
+            input,
             position: 0,
             next_position: 0,
             c: '\0',
             line: 1,
+            column: 1,
+            offset: 0,
+            file_id: FileId::default(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            previous: None,
+            templates: Vec::new(),
+            symbols: NameMap::default(),
+            dialect: Dialect::default(),
+            keep_trivia: false,
+            finished: false,
         };
 
-        new_lexer.read_char();
+        new_lexer.c = new_lexer.char_at(0);
+        if !input.is_empty() {
+            new_lexer.next_position = new_lexer.c.len_utf8();
+        }
+
+        new_lexer
+    }
+
+    /// Moves the lexer to `checkpoint`, its input starting `offset` bytes into the file.
+    fn resume(self, offset: usize, file_id: FileId, checkpoint: Checkpoint) -> Lexer<'a> {
+        let mut new_lexer = self;
+        new_lexer.position = checkpoint.position;
+        new_lexer.c = new_lexer.char_at(checkpoint.position);
+        new_lexer.next_position =
+            (checkpoint.position + new_lexer.c.len_utf8()).min(new_lexer.input.len());
+        new_lexer.line = checkpoint.location.line;
+        new_lexer.column = checkpoint.location.column;
+        new_lexer.offset = offset;
+        new_lexer.file_id = file_id;
+        new_lexer.previous = checkpoint.previous;
+        new_lexer.templates = checkpoint.templates;
+
+        new_lexer
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            position: self.position,
+            location: self.location(),
+            previous: self.previous,
+            templates: self.templates.clone(),
+        }
+    }
+
+    /// Creates a lexer over a file registered in `source_map`, so that token spans refer to it.
+    pub fn for_file(source_map: &'a SourceMap, file_id: FileId) -> Lexer<'a> {
+        let source = source_map.get(file_id).map_or("", |file| file.source());
+
+        let mut new_lexer = Lexer::new(source);
+        new_lexer.file_id = file_id;
 
         new_lexer
     }
 
+    /// Makes `next_token` attach whitespace and comments to the tokens as trivia, so that the
+    /// input can be rebuilt byte for byte from them.
+    pub fn with_trivia(mut self) -> Self {
+        self.keep_trivia = true;
+        self
+    }
+
+    /// Lexes the input as `dialect`, words that are keywords elsewhere being reported as
+    /// errors and lexed as identifiers.
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    pub fn dialect(&self) -> &Dialect {
+        &self.dialect
+    }
+
     pub fn next_token(&mut self) -> Token {
-        self.maybe_read_whitespace();
-
-        let mut new_token: Token = Token {
-            t: TokenType::KEYWORD(Keyword::UNDEFINED),
-            value: self.c.to_string(),
-            filename: None,
-            line: Some(self.line),
-            position: Some(self.position),
+        if !self.keep_trivia {
+            return self.next_lexeme().into();
+        }
+
+        let leading = self.read_trivia(false);
+        let lexeme = self.next_lexeme();
+        let text = lexeme.text.to_owned();
+        let trailing = self.read_trivia(true);
+
+        let mut token: Token = lexeme.into();
+        token.trivia = Some(Box::new(TokenTrivia {
+            leading,
+            text,
+            trailing,
+        }));
+
+        token
+    }
+
+    /// Reads the next token without copying its text out of the input.
+    pub fn next_lexeme(&mut self) -> Lexeme<'a> {
+        let in_template = matches!(self.templates.last(), Some(TemplateMode::Text(..)));
+        if !in_template {
+            self.maybe_read_whitespace();
+        }
+
+        let start = self.mark();
+        let (t, value) = if in_template {
+            self.read_template_text()
+        } else {
+            self.read_lexeme()
         };
+        let span = self.span_from(start);
+        let text = &self.input[start.1..self.position];
+        self.previous = Some((t, span.byte_range.end));
 
-        match self.c {
-            '=' => {
-                if self.peek_next_char() == '=' {
-                    self.read_char(); // Consume '='
-                    new_token.value = "==".to_owned();
-                    new_token.t = TokenType::CMP(Cmp::EQUAL);
-                } else {
-                    new_token.t = TokenType::ASSIGN;
-                }
-            }
-            ';' => new_token.t = TokenType::SEMICOLON,
+        if t == TokenType::ILLEGAL {
+            let c = text.chars().next().unwrap_or('\0');
+            self.errors
+                .push(LexError::unknown_character(c, span.clone()));
+        }
+
+        let value = value.unwrap_or(Cow::Borrowed(text));
+        let symbol = (t == TokenType::IDENT).then(|| {
+            *self
+                .symbols
+                .entry(text)
+                .or_insert_with(|| Symbol::intern(&value))
+        });
+
+        Lexeme {
+            t,
+            text,
+            value,
+            span,
+            symbol,
+        }
+    }
+
+    /// Reads the token at the cursor, returning its type and, when it differs from the source
+    /// text, its value.
+    fn read_lexeme(&mut self) -> (TokenType, Option<Cow<'a, str>>) {
+        let t = match self.c {
+            ';' => TokenType::SEMICOLON,
             ':' => {
                 if self.peek_next_char() == ':' {
                     self.read_char();
-                    new_token.value.push(':');
-                    new_token.t = TokenType::SCOPE;
+                    TokenType::SCOPE
                 } else {
-                    new_token.t = TokenType::COLON;
+                    TokenType::COLON
                 }
             }
-            '(' => new_token.t = TokenType::LPAREN,
-            ')' => new_token.t = TokenType::RPAREN,
+            '(' => TokenType::LPAREN,
+            ')' => TokenType::RPAREN,
             '.' => {
                 if self.peek_next_char() == '.' {
                     self.read_char();
 
-                    new_token.value = "..".to_owned();
                     if self.peek_next_char() == '=' {
                         self.read_char();
-
-                        new_token.value.push('=');
-                        new_token.t = TokenType::IRANGE;
-                    } else {
-                        new_token.t = TokenType::RANGE;
-                    }
-                } else {
-                    new_token.t = TokenType::DOT;
-                }
-            }
-            ',' => new_token.t = TokenType::COMMA,
-            '+' | '-' | '*' | '/' | '%' => {
-                if let Some(arithmetic) = Arithmetic::from_str(self.c.to_string().as_str()) {
-                    if self.peek_next_char() == self.c {
-                        let double = format!("{}{}", self.c, self.c);
-                        if let Some(repeated_arithmetic) = Arithmetic::from_str(double.as_str()) {
-                            self.read_char();
-                            new_token.value = double;
-                            new_token.t = TokenType::ARITHMETIC(repeated_arithmetic);
-                        }
+                        TokenType::IRANGE
                     } else {
-                        new_token.t = TokenType::ARITHMETIC(arithmetic);
+                        TokenType::RANGE
                     }
                 } else {
-                    new_token.t = TokenType::ILLEGAL;
+                    TokenType::DOT
                 }
             }
-            '&' | '|' | '~' | '^' => {
-                if let Some(bitop) = Bitop::from_str(self.c.to_string().as_str()) {
-                    if self.peek_next_char() == self.c {
-                        let double = format!("{}{}", self.c, self.c);
-                        if let Some(logicop) = LogicOp::from_str(double.as_str()) {
-                            self.read_char();
-                            new_token.value = double;
-                            new_token.t = TokenType::LOGICOP(logicop);
-                        }
-                    } else {
-                        new_token.t = TokenType::BITOP(bitop);
-                    }
-                } else {
-                    new_token.t = TokenType::ILLEGAL;
-                }
+            ',' => TokenType::COMMA,
+            '/' if self.is_doc_comment() => {
+                return (
+                    TokenType::DOC_COMMENT,
+                    Some(Cow::Borrowed(self.read_line_comment())),
+                );
             }
-            '!' => {
-                if self.peek_next_char() == '=' {
-                    self.read_char(); // Consume '='
-                    new_token.t = TokenType::CMP(Cmp::NEQUAL);
-                } else {
-                    new_token.t = TokenType::LOGICOP(LogicOp::NOT);
-                }
+            '!' if self.is_macro_bang() => TokenType::BANG,
+            '=' | '!' | '<' | '>' | '+' | '-' | '*' | '/' | '%' | '&' | '|' | '~' | '^' => {
+                self.read_operator()
             }
-            '{' => new_token.t = TokenType::LBRACE,
-            '}' => new_token.t = TokenType::RBRACE,
-            '<' => {
-                if self.peek_next_char() == '=' {
-                    self.read_char(); // Consume '='
-                    new_token.t = TokenType::CMP(Cmp::LE);
-                } else {
-                    new_token.t = TokenType::CMP(Cmp::LT);
+            '{' => {
+                if let Some(TemplateMode::Interpolation(depth)) = self.templates.last_mut() {
+                    *depth += 1;
                 }
+                TokenType::LBRACE
             }
-            '>' => {
-                if self.peek_next_char() == '=' {
-                    self.read_char(); // Consume '='
-                    new_token.t = TokenType::CMP(Cmp::GE);
-                } else {
-                    new_token.t = TokenType::CMP(Cmp::GT);
+            '}' => match self.templates.last_mut() {
+                Some(TemplateMode::Interpolation(0)) => {
+                    self.templates.pop();
+                    TokenType::INTERP_END
+                }
+                Some(TemplateMode::Interpolation(depth)) => {
+                    *depth -= 1;
+                    TokenType::RBRACE
                 }
+                _ => TokenType::RBRACE,
+            },
+            '[' => TokenType::LBRACKET,
+            ']' => TokenType::RBRACKET,
+            '?' => TokenType::QUESTION,
+            '@' => TokenType::AT,
+            '#' => TokenType::HASH,
+            '$' => TokenType::DOLLAR,
+            '"' if self.is_multiline_string() => {
+                return (TokenType::STRING, Some(self.read_multiline_string()))
+            }
+            '"' => return (TokenType::STRING, Some(self.read_string())),
+            '\'' => return (TokenType::CHAR, Some(self.read_char_literal())),
+            '`' if self.dialect.template_strings => {
+                let opening = TemplateMode::Text(self.location(), self.offset + self.position);
+                self.templates.push(opening);
+                TokenType::BACKTICK
+            }
+            '`' => TokenType::BACKTICK,
+            '\0' if self.is_at_end() => return (TokenType::EOF, Some(Cow::Borrowed("\0"))),
+            'r' if self.is_raw_string() => {
+                return (TokenType::STRING, Some(self.read_raw_string()))
             }
-            '"' => new_token.t = TokenType::DQUOTE,
-            '\'' => new_token.t = TokenType::QUOTE,
-            '`' => new_token.t = TokenType::BACKTICK,
-            '\0' => new_token.t = TokenType::EOF,
-            'a'..='z' | 'A'..='Z' | '_' => {
-                let identifier = self.maybe_read_identifier();
+            'a'..='z' | 'A'..='Z' | '_' => return self.read_identifier(),
+            '0'..='9' => {
+                let (t, number) = self.read_number();
 
-                if let Some(keyword) = Keyword::from_str(&identifier) {
-                    new_token.t = TokenType::KEYWORD(keyword);
-                } else {
-                    new_token.t = TokenType::IDENT;
+                return (t, Some(number));
+            }
+            c if c.is_xid_start() => return self.read_identifier(),
+            _ => TokenType::ILLEGAL,
+        };
+
+        self.read_char();
+
+        (t, None)
+    }
+
+    /// Reads a part of a template string: its closing backtick, the `${` opening an
+    /// interpolation or the text up to either of them.
+    fn read_template_text(&mut self) -> (TokenType, Option<Cow<'a, str>>) {
+        if self.c == '`' {
+            self.templates.pop();
+            self.read_char();
+            return (TokenType::BACKTICK, None);
+        }
+
+        if self.c == '$' && self.peek_next_char() == '{' {
+            self.templates.push(TemplateMode::Interpolation(0));
+            self.read_char();
+            self.read_char();
+            return (TokenType::INTERP_START, None);
+        }
+
+        if self.is_at_end() {
+            if let Some(TemplateMode::Text(start, offset)) = self.templates.last() {
+                let span = Span::new(
+                    self.file_id,
+                    *start,
+                    self.location(),
+                    *offset..self.offset + self.position,
+                );
+                self.errors.push(LexError::unterminated_template(span));
+            }
+
+            // Every enclosing template is left open as well, don't report them again.
+            self.templates.clear();
+            return self.read_lexeme();
+        }
+
+        let input = self.input;
+        let content = self.position;
+        let mut escaped: Option<String> = None;
+
+        while !(self.c == '`'
+            || (self.c == '$' && self.peek_next_char() == '{')
+            || self.is_at_end())
+        {
+            if self.c == '\\' {
+                let value = escaped.get_or_insert_with(|| input[content..self.position].to_owned());
+
+                if matches!(self.peek_next_char(), '`' | '$') {
+                    self.read_char(); // Consume '\'
+                    value.push(self.c);
+                    self.read_char();
+                } else if let Some(c) = self.read_escape() {
+                    value.push(c);
+                }
+            } else {
+                if let Some(value) = &mut escaped {
+                    value.push(self.c);
                 }
+                self.read_char();
+            }
+        }
+
+        let value = escaped.map_or(Cow::Borrowed(&input[content..self.position]), Cow::Owned);
+
+        (TokenType::TEMPLATE_STRING, Some(value))
+    }
+
+    /// Reads the longest operator starting at the cursor, leaving its last character to be
+    /// consumed by the caller.
+    fn read_operator(&mut self) -> TokenType {
+        for len in (1..=3).rev() {
+            let operator = self.input.get(self.position..self.position + len);
 
-                new_token.value = identifier;
+            if let Some(t) = operator.and_then(|operator| self.operator(operator)) {
+                for _ in 1..len {
+                    self.read_char();
+                }
 
-                return new_token;
+                return t;
             }
-            '0'..='9' => {
-                let number = self.maybe_read_number();
-                return Token {
-                    t: TokenType::INT,
-                    value: number.to_string(),
-                    line: Some(self.line),
-                    position: Some(self.position),
-                    filename: None,
-                };
-            }
-            _ => new_token.t = TokenType::ILLEGAL,
-        };
+        }
 
-        self.read_char();
+        TokenType::ILLEGAL
+    }
 
-        new_token
+    /// A '!' right after an identifier, as in `println!(...)`, invokes a macro.
+    fn is_macro_bang(&self) -> bool {
+        self.dialect.macros
+            && self.peek_next_char() != '='
+            && self.previous == Some((TokenType::IDENT, self.offset + self.position))
     }
 
-    pub fn read_char(&mut self) -> &Lexer {
-        if self.next_position >= self.input.len() {
-            self.c = '\0';
-        } else {
-            self.c = self.input[self.next_position];
+    fn operator(&self, text: &str) -> Option<TokenType> {
+        match text {
+            "=" => Some(TokenType::ASSIGN),
+            "->" => Some(TokenType::ARROW),
+            "=>" => Some(TokenType::FATARROW),
+            "!" => Some(TokenType::LOGICOP(LogicOp::NOT)),
+            // Only match symbols, `from_str` also accepts names such as "AND".
+            _ if text.chars().any(char::is_alphanumeric) => None,
+            _ => AssignOp::from_str(text)
+                .filter(|_| self.dialect.compound_assignment)
+                .map(TokenType::ASSIGNOP)
+                .or_else(|| Cmp::from_str(text).map(TokenType::CMP))
+                .or_else(|| Arithmetic::from_str(text).map(TokenType::ARITHMETIC))
+                .or_else(|| Bitop::from_str(text).map(TokenType::BITOP))
+                .or_else(|| LogicOp::from_str(text).map(TokenType::LOGICOP)),
+        }
+    }
+
+    pub fn read_char(&mut self) -> &Lexer<'a> {
+        // Step over the current character, unless we are already past the end of the input.
+        if !self.is_at_end() {
+            if self.c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
         }
 
         self.position = self.next_position;
-        self.next_position += 1;
+        self.c = self.char_at(self.position);
+
+        if !self.is_at_end() {
+            self.next_position = self.position + self.c.len_utf8();
+        }
+
         self
     }
 
-    pub fn peek_next_char(&mut self) -> char {
-        if self.next_position >= self.input.len() {
-            '\0'
+    pub fn peek_next_char(&self) -> char {
+        self.char_at(self.next_position)
+    }
+
+    /// Decodes the character starting at byte `position`, ASCII being the fast path.
+    fn char_at(&self, position: usize) -> char {
+        match self.input.as_bytes().get(position) {
+            Some(&byte) if byte.is_ascii() => byte as char,
+            Some(_) => self.input[position..].chars().next().unwrap_or('\0'),
+            None => '\0',
+        }
+    }
+
+    fn location(&self) -> Location {
+        Location::new(self.line, self.column)
+    }
+
+    /// Captures the current location and byte offset, to be turned into a span with `span_from`.
+    fn mark(&self) -> (Location, usize) {
+        (self.location(), self.position)
+    }
+
+    fn span_from(&self, (start, position): (Location, usize)) -> Span {
+        Span::new(
+            self.file_id,
+            start,
+            self.location(),
+            self.offset + position..self.offset + self.position,
+        )
+    }
+
+    fn peek_nth_char(&self, n: usize) -> char {
+        self.input
+            .get(self.next_position..)
+            .and_then(|rest| rest.chars().nth(n))
+            .unwrap_or('\0')
+    }
+
+    /// Reads an identifier or keyword following UAX #31, its value being NFC normalised.
+    fn read_identifier(&mut self) -> (TokenType, Option<Cow<'a, str>>) {
+        let start = self.mark();
+        let identifier = self.maybe_read_identifier();
+
+        let value = if identifier.is_ascii() || is_nfc(identifier) {
+            Cow::Borrowed(identifier)
         } else {
-            self.input[self.next_position]
+            Cow::Owned(identifier.nfc().collect())
+        };
+
+        if !value.is_ascii() && Self::is_confusable(&value) {
+            self.warnings.push(LexError::confusable_identifier(
+                &value,
+                self.span_from(start),
+            ));
+        }
+
+        if self.dialect.is_foreign_keyword(&value) {
+            self.errors.push(LexError::foreign_keyword(
+                &value,
+                self.dialect.name,
+                self.span_from(start),
+            ));
         }
+
+        let t = match self.dialect.keyword(&value) {
+            Some(keyword) => TokenType::KEYWORD(keyword),
+            None => TokenType::IDENT,
+        };
+
+        (t, Some(value))
     }
 
-    fn maybe_read_identifier(&mut self) -> String {
+    fn maybe_read_identifier(&mut self) -> &'a str {
         let position = self.position;
 
-        while self.c.is_alphanumeric() || self.c == '_' {
+        while self.c.is_ascii_alphanumeric()
+            || self.c == '_'
+            || (!self.c.is_ascii() && self.c.is_xid_continue())
+        {
             self.read_char();
         }
 
-        self.input[position..self.position]
-            .into_iter()
-            .collect::<String>()
+        &self.input[position..self.position]
     }
 
-    fn maybe_read_number(&mut self) -> String {
+    /// Flags identifiers mixing scripts that would read as a single script once confusable
+    /// characters are replaced, such as a Cyrillic 'а' in an otherwise Latin name.
+    fn is_confusable(identifier: &str) -> bool {
+        !identifier.is_single_script()
+            && skeleton(identifier).collect::<String>().is_single_script()
+    }
+
+    fn read_number(&mut self) -> (TokenType, Cow<'a, str>) {
+        let start = self.mark();
         let position = self.position;
+        let mut t = TokenType::INT;
+
+        let radix = match (self.c, self.peek_nth_char(0)) {
+            ('0', 'x') => 16,
+            ('0', 'o') => 8,
+            ('0', 'b') => 2,
+            _ => 10,
+        };
+
+        if radix != 10 {
+            self.read_char(); // Consume '0'
+            self.read_char(); // Consume the radix prefix
+        }
+
+        let digits_position = self.position;
+
+        if radix == 16 {
+            while self.c.is_ascii_hexdigit() || self.c == '_' {
+                self.read_char();
+            }
+        } else {
+            // Binary and octal literals read every decimal digit so that `0b102` reports the '2'.
+            self.read_digits();
+        }
+
+        // Only a '.' followed by a digit starts a fraction, so `0..10` stays a RANGE.
+        if radix == 10 && self.c == '.' && self.peek_nth_char(0).is_ascii_digit() {
+            t = TokenType::FLOAT;
+            self.read_char(); // Consume '.'
+            self.read_digits();
+        }
 
-        while self.c.is_numeric() {
+        if radix == 10 && matches!(self.c, 'e' | 'E') {
+            let next = self.peek_nth_char(0);
+            let signed = matches!(next, '+' | '-') && self.peek_nth_char(1).is_ascii_digit();
+
+            if next.is_ascii_digit() || signed {
+                t = TokenType::FLOAT;
+                self.read_char(); // Consume 'e'
+                if signed {
+                    self.read_char();
+                }
+                self.read_digits();
+            }
+        }
+
+        let suffix_position = self.position;
+        while self.c.is_alphanumeric() || self.c == '_' {
             self.read_char();
         }
 
-        self.input[position..self.position]
-            .into_iter()
-            .collect::<String>()
+        let literal = &self.input[position..self.position];
+        let digits = Self::without_separators(&self.input[digits_position..suffix_position]);
+        let suffix = &self.input[suffix_position..self.position];
+
+        let number_suffix = NumberSuffix::from_str(suffix);
+        let malformed = match number_suffix {
+            _ if suffix.is_empty() => None,
+            Some(s) if s.is_float() && radix != 10 => {
+                Some(format!("float suffix on a base {} literal", radix))
+            }
+            Some(s) if s.is_float() => {
+                t = TokenType::FLOAT;
+                None
+            }
+            Some(_) if t == TokenType::INT => None,
+            Some(_) => Some("integer suffix on a float literal".to_owned()),
+            None => Some(format!("unknown suffix '{}'", suffix)),
+        };
+
+        let span = self.span_from(start);
+
+        if let Some(reason) = malformed {
+            self.errors
+                .push(LexError::malformed_number(literal, &reason, span));
+            return (t, Cow::Borrowed(literal));
+        }
+
+        if t == TokenType::FLOAT {
+            return (t, Self::without_separators(literal));
+        }
+
+        // A decimal literal without separators or leading zeros is already normalised.
+        let normalised = radix == 10
+            && digits.len() == suffix_position - position
+            && (digits.len() == 1 || !digits.starts_with('0'));
+
+        match Self::check_integer(literal, &digits, radix, number_suffix, span) {
+            Ok(_) if normalised => (t, Cow::Borrowed(literal)),
+            Ok(value) => (t, Cow::Owned(format!("{}{}", value, suffix))),
+            Err(error) => {
+                self.errors.push(error);
+                (t, Cow::Borrowed(literal))
+            }
+        }
     }
 
-    fn maybe_read_until<F>(&mut self, mut condition: F) -> String
-    where
-        F: FnMut() -> bool,
-    {
-        let position = self.position;
+    /// Validates the digits of an integer literal and returns its value, which must fit the
+    /// suffix's width (i32 when there is no suffix).
+    fn check_integer(
+        literal: &str,
+        digits: &str,
+        radix: u32,
+        suffix: Option<NumberSuffix>,
+        span: Span,
+    ) -> Result<u128, LexError> {
+        if digits.is_empty() {
+            return Err(LexError::malformed_number(literal, "missing digits", span));
+        }
 
-        while condition() {
+        if let Some(digit) = digits.chars().find(|c| !c.is_digit(radix)) {
+            return Err(LexError::invalid_digit(digit, radix, span));
+        }
+
+        let suffix = suffix.unwrap_or(NumberSuffix::I32);
+        let value = u128::from_str_radix(digits, radix)
+            .ok()
+            .filter(|value| *value <= suffix.max_literal())
+            .ok_or_else(|| LexError::integer_overflow(literal, suffix, span))?;
+
+        Ok(value)
+    }
+
+    fn without_separators(literal: &str) -> Cow<'_, str> {
+        if literal.contains('_') {
+            Cow::Owned(literal.replace('_', ""))
+        } else {
+            Cow::Borrowed(literal)
+        }
+    }
+
+    fn read_digits(&mut self) {
+        while self.c.is_ascii_digit() || self.c == '_' {
             self.read_char();
         }
+    }
 
-        self.input[position..self.position]
-            .into_iter()
-            .collect::<String>()
+    fn is_raw_string(&self) -> bool {
+        self.c == 'r'
+            && self.input[self.next_position..]
+                .trim_start_matches('#')
+                .starts_with('"')
     }
 
-    fn maybe_read_whitespace(&mut self) {
+    /// Reads `r"..."` or `r#"..."#`, without processing escapes. The closing quote must be
+    /// followed by as many '#' as the opening one is preceded by.
+    fn read_raw_string(&mut self) -> Cow<'a, str> {
+        let start = self.mark();
+        let input = self.input;
+
+        self.read_char(); // Consume 'r'
+
+        let mut hashes = 0;
+        while self.c == '#' {
+            hashes += 1;
+            self.read_char();
+        }
+
+        self.read_char(); // Consume the opening '"'
+
+        let content = self.position;
+
         loop {
+            if self.is_at_end() {
+                self.errors
+                    .push(LexError::unterminated_string(self.span_from(start)));
+                return Cow::Borrowed(&input[content..self.position]);
+            }
+
+            let closing_hashes = input.as_bytes()[self.next_position..]
+                .iter()
+                .take_while(|byte| **byte == b'#')
+                .count();
+
+            if self.c == '"' && closing_hashes >= hashes {
+                let end = self.position;
+
+                for _ in 0..=hashes {
+                    self.read_char(); // Consume the closing '"' and its '#'
+                }
+
+                return Cow::Borrowed(&input[content..end]);
+            }
+
+            self.read_char();
+        }
+    }
+
+    fn is_multiline_string(&self) -> bool {
+        self.input[self.position..].starts_with("\"\"\"")
+    }
+
+    /// Reads a `"""` string. A line break right after the opening quotes and a blank line
+    /// before the closing ones are dropped, and so is the indentation common to every other
+    /// non-blank line. Escapes are processed after the indentation has been removed.
+    fn read_multiline_string(&mut self) -> Cow<'a, str> {
+        let start = self.mark();
+
+        for _ in 0..3 {
+            self.read_char(); // Consume the opening '"""'
+        }
+
+        let Some(raw) = Self::multiline_content(&self.input[self.position..]) else {
+            while !self.is_at_end() {
+                self.read_char();
+            }
+            self.errors
+                .push(LexError::unterminated_string(self.span_from(start)));
+            return Cow::Borrowed("");
+        };
+
+        let skip_first_line = raw.starts_with('\n') || raw.starts_with("\r\n");
+        let lines: Vec<&str> = raw.split('\n').collect();
+        let last_line_blank = lines.len() > 1 && lines[lines.len() - 1].trim().is_empty();
+
+        let indent = lines
+            .iter()
+            .skip(usize::from(skip_first_line))
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+            .min()
+            .unwrap_or(0);
+
+        if skip_first_line {
+            if self.c == '\r' {
+                self.read_char();
+            }
+            self.read_char(); // Consume '\n'
+        }
+
+        let mut value = String::new();
+        let mut last_newline = None;
+        let mut line_start = true;
+
+        while !self.is_multiline_string() && !self.is_at_end() {
+            if line_start {
+                line_start = false;
+
+                let mut skipped = 0;
+                while skipped < indent && matches!(self.c, ' ' | '\t') {
+                    skipped += 1;
+                    self.read_char();
+                }
+
+                continue;
+            }
+
             match self.c {
-                '\n' | '\r' => {
-                    self.line += 1;
+                '\\' => {
+                    if let Some(escaped) = self.read_escape() {
+                        value.push(escaped);
+                    }
+                }
+                '\r' if self.peek_next_char() == '\n' => {
+                    self.read_char(); // Line endings are normalised to '\n'
+                }
+                '\n' => {
+                    last_newline = Some(value.len());
+                    line_start = true;
+                    value.push('\n');
+                    self.read_char();
+                }
+                c => {
+                    value.push(c);
                     self.read_char();
                 }
-                ' ' | '\t' => {
+            }
+        }
+
+        for _ in 0..3 {
+            self.read_char(); // Consume the closing '"""'
+        }
+
+        if last_line_blank {
+            if let Some(newline) = last_newline {
+                value.truncate(newline);
+            }
+        }
+
+        Cow::Owned(value)
+    }
+
+    /// Returns the source between the opening `"""` (already consumed) and the closing one, or
+    /// `None` when the string is never closed.
+    fn multiline_content(rest: &str) -> Option<&str> {
+        let mut chars = rest.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' if rest[index..].starts_with("\"\"\"") => return Some(&rest[..index]),
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn read_string(&mut self) -> Cow<'a, str> {
+        let start = self.mark();
+        let input = self.input;
+
+        self.read_char(); // Consume the opening '"'
+
+        // The contents are borrowed from the input until an escape sequence forces a copy.
+        let content = self.position;
+        let mut escaped: Option<String> = None;
+
+        let end = loop {
+            if self.is_at_end() {
+                self.errors
+                    .push(LexError::unterminated_string(self.span_from(start)));
+                break self.position;
+            }
+
+            match self.c {
+                '"' => {
+                    let end = self.position;
+                    self.read_char(); // Consume the closing '"'
+                    break end;
+                }
+                '\\' => {
+                    let value =
+                        escaped.get_or_insert_with(|| input[content..self.position].to_owned());
+                    if let Some(c) = self.read_escape() {
+                        value.push(c);
+                    }
+                }
+                c => {
+                    if let Some(value) = &mut escaped {
+                        value.push(c);
+                    }
+                    self.read_char();
+                }
+            }
+        };
+
+        escaped.map_or(Cow::Borrowed(&input[content..end]), Cow::Owned)
+    }
+
+    fn read_char_literal(&mut self) -> Cow<'a, str> {
+        let start = self.mark();
+        let input = self.input;
+        let errors = self.errors.len();
+
+        self.read_char(); // Consume the opening '\''
+
+        let content = self.position;
+        let mut escaped: Option<String> = None;
+
+        while self.c != '\'' {
+            if self.is_at_end() || self.c == '\n' {
+                self.errors
+                    .push(LexError::unterminated_char(self.span_from(start)));
+                return escaped.map_or(Cow::Borrowed(&input[content..self.position]), Cow::Owned);
+            }
+
+            if self.c == '\\' {
+                let value = escaped.get_or_insert_with(|| input[content..self.position].to_owned());
+                if let Some(c) = self.read_escape() {
+                    value.push(c);
+                }
+            } else {
+                if let Some(value) = &mut escaped {
+                    value.push(self.c);
+                }
+                self.read_char();
+            }
+        }
+
+        let value = escaped.map_or(Cow::Borrowed(&input[content..self.position]), Cow::Owned);
+
+        self.read_char(); // Consume the closing '\''
+
+        // A bad escape has already been reported, don't pile a length error on top of it.
+        if self.errors.len() == errors && value.chars().count() != 1 {
+            self.errors.push(LexError::invalid_char_literal(
+                &value,
+                self.span_from(start),
+            ));
+        }
+
+        value
+    }
+
+    fn read_escape(&mut self) -> Option<char> {
+        let start = self.mark();
+
+        self.read_char(); // Consume '\'
+
+        let escaped = match self.c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'u' => return self.read_unicode_escape(start),
+            // Leave the missing closing quote to the caller.
+            _ if self.is_at_end() => return None,
+            c => {
+                self.errors.push(LexError::invalid_escape(
+                    &format!("\\{}", c),
+                    self.span_from(start),
+                ));
+                self.read_char();
+                return None;
+            }
+        };
+
+        self.read_char();
+
+        Some(escaped)
+    }
+
+    fn read_unicode_escape(&mut self, start: (Location, usize)) -> Option<char> {
+        self.read_char(); // Consume 'u'
+
+        if self.c != '{' {
+            self.errors
+                .push(LexError::invalid_escape("\\u", self.span_from(start)));
+            return None;
+        }
+
+        self.read_char(); // Consume '{'
+
+        let position = self.position;
+        while self.c.is_ascii_hexdigit() {
+            self.read_char();
+        }
+        let digits = &self.input[position..self.position];
+
+        if self.c != '}' {
+            self.errors.push(LexError::invalid_escape(
+                &format!("\\u{{{}", digits),
+                self.span_from(start),
+            ));
+            return None;
+        }
+
+        self.read_char(); // Consume '}'
+
+        // At most six hex digits, and the value must be a Unicode scalar (no surrogates).
+        let scalar = u32::from_str_radix(digits, 16)
+            .ok()
+            .filter(|_| digits.len() <= 6)
+            .and_then(char::from_u32);
+
+        if scalar.is_none() {
+            self.errors.push(LexError::invalid_escape(
+                &format!("\\u{{{}}}", digits),
+                self.span_from(start),
+            ));
+        }
+
+        scalar
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    pub fn errors(&self) -> &[LexError] {
+        self.errors.as_ref()
+    }
+
+    /// Lints that don't prevent lexing, such as confusable identifiers.
+    pub fn warnings(&self) -> &[LexError] {
+        self.warnings.as_ref()
+    }
+
+    /// Skips whitespace and comments, stopping at doc comments since those become tokens.
+    fn maybe_read_whitespace(&mut self) {
+        loop {
+            match self.c {
+                ' ' | '\t' | '\n' | '\r' => {
                     self.read_char();
                 }
+                '/' if self.peek_next_char() == '/' && !self.is_doc_comment() => {
+                    self.read_line_comment();
+                }
+                '/' if self.peek_next_char() == '*' => {
+                    self.read_block_comment();
+                }
                 _ => break,
             }
         }
     }
+
+    /// Reads the whitespace and comments at the cursor. Trailing trivia stops before the end of
+    /// the line. Template text is never trivia.
+    fn read_trivia(&mut self, trailing: bool) -> Vec<Trivia> {
+        let mut trivia = Vec::new();
+
+        if matches!(self.templates.last(), Some(TemplateMode::Text(..))) {
+            return trivia;
+        }
+
+        loop {
+            let position = self.position;
+
+            let kind = match self.c {
+                '\n' if trailing => break,
+                ' ' | '\t' | '\n' | '\r' => {
+                    while matches!(self.c, ' ' | '\t' | '\r') || (self.c == '\n' && !trailing) {
+                        self.read_char();
+                    }
+                    TriviaKind::WHITESPACE
+                }
+                '/' if self.peek_next_char() == '/' && !self.is_doc_comment() => {
+                    // Keep a '\r' ending the line with the whitespace that follows.
+                    while self.c != '\n' && !self.is_at_end() {
+                        if self.c == '\r' && self.peek_next_char() == '\n' {
+                            break;
+                        }
+                        self.read_char();
+                    }
+                    TriviaKind::LINE_COMMENT
+                }
+                '/' if self.peek_next_char() == '*' => {
+                    self.read_block_comment();
+                    TriviaKind::BLOCK_COMMENT
+                }
+                _ => break,
+            };
+
+            trivia.push(Trivia {
+                kind,
+                text: self.input[position..self.position].to_owned(),
+            });
+        }
+
+        trivia
+    }
+
+    fn is_doc_comment(&self) -> bool {
+        // `///` and `//!` are doc comments, `////` is a regular comment again.
+        self.c == '/'
+            && self.peek_nth_char(0) == '/'
+            && match self.peek_nth_char(1) {
+                '!' => true,
+                '/' => self.peek_nth_char(2) != '/',
+                _ => false,
+            }
+    }
+
+    fn read_line_comment(&mut self) -> &'a str {
+        let position = self.position;
+
+        while self.c != '\n' && !self.is_at_end() {
+            self.read_char();
+        }
+
+        self.input[position..self.position].trim_end_matches('\r')
+    }
+
+    fn read_block_comment(&mut self) {
+        let start = self.mark();
+        let mut depth = 0;
+
+        loop {
+            if self.is_at_end() {
+                self.errors
+                    .push(LexError::unterminated_comment(self.span_from(start)));
+                break;
+            }
+
+            match (self.c, self.peek_next_char()) {
+                ('/', '*') => {
+                    depth += 1;
+                    self.read_char();
+                }
+                ('*', '/') => {
+                    depth -= 1;
+                    self.read_char();
+                }
+                _ => {}
+            }
+
+            self.read_char();
+
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    /// Yields every token up to and including EOF, then `None`.
+    fn next(&mut self) -> Option<Token> {
+        if self.finished {
+            return None;
+        }
+
+        let token = self.next_token();
+        self.finished = token.t == TokenType::EOF;
+
+        Some(token)
+    }
 }
@@ -0,0 +1,130 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::{enums::token_type::TokenType, span::FileId, token::Token};
+
+use super::{dialect::Dialect, error::LexError, Checkpoint, Lexer};
+
+/// Minimum number of bytes pulled from the reader each time the buffer runs dry.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Lexes tokens out of a reader, only keeping the part of the source that has not been
+/// consumed yet in memory.
+pub struct TokenStream<R> {
+    reader: R,
+    buffer: String,
+    checkpoint: Checkpoint,
+    offset: usize,
+    file_id: FileId,
+    dialect: Dialect,
+    exhausted: bool,
+    finished: bool,
+    errors: Vec<LexError>,
+    warnings: Vec<LexError>,
+    io_error: Option<io::Error>,
+}
+
+impl<R: BufRead> TokenStream<R> {
+    pub fn new(reader: R) -> Self {
+        TokenStream {
+            reader,
+            buffer: String::new(),
+            checkpoint: Checkpoint::default(),
+            offset: 0,
+            file_id: FileId::default(),
+            dialect: Dialect::default(),
+            exhausted: false,
+            finished: false,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            io_error: None,
+        }
+    }
+
+    pub fn with_file_id(mut self, file_id: FileId) -> Self {
+        self.file_id = file_id;
+        self
+    }
+
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    pub fn errors(&self) -> &[LexError] {
+        self.errors.as_ref()
+    }
+
+    pub fn warnings(&self) -> &[LexError] {
+        self.warnings.as_ref()
+    }
+
+    /// The error that stopped reading early, if any. The input is then lexed as if it ended
+    /// there.
+    pub fn io_error(&self) -> Option<&io::Error> {
+        self.io_error.as_ref()
+    }
+
+    /// Drops the consumed source and reads whole lines until at least `CHUNK_SIZE` bytes were
+    /// added or the reader is exhausted.
+    fn fill(&mut self) {
+        self.buffer.drain(..self.checkpoint.position);
+        self.offset += self.checkpoint.position;
+        self.checkpoint.position = 0;
+
+        let len = self.buffer.len();
+        while self.buffer.len() - len < CHUNK_SIZE {
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => {
+                    self.exhausted = true;
+                    break;
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    self.io_error = Some(error);
+                    self.exhausted = true;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for TokenStream<R> {
+    type Item = Token;
+
+    /// Yields every token up to and including EOF, then `None`.
+    fn next(&mut self) -> Option<Token> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            let mut lexer = Lexer::new(&self.buffer).with_dialect(self.dialect).resume(
+                self.offset,
+                self.file_id,
+                self.checkpoint.clone(),
+            );
+            let token = lexer.next_token();
+
+            // A token running into the end of the buffer may go on in the unread input, in which
+            // case it is lexed again (errors and warnings included) once more of it has been read.
+            if lexer.position < self.buffer.len() || self.exhausted {
+                self.checkpoint = lexer.checkpoint();
+                self.errors.append(&mut lexer.errors);
+                self.warnings.append(&mut lexer.warnings);
+                self.finished = token.t == TokenType::EOF;
+
+                return Some(token);
+            }
+
+            self.fill();
+        }
+    }
+}
+
+impl<'a> Lexer<'a> {
+    /// Creates a token stream lexing `reader` incrementally instead of loading it up front.
+    pub fn from_reader<R: Read>(reader: R) -> TokenStream<BufReader<R>> {
+        TokenStream::new(BufReader::new(reader))
+    }
+}
@@ -1,19 +1,24 @@
 #[cfg(test)]
+// Expected token tables are built one push per source line for readability.
+#[allow(clippy::vec_init_then_push)]
 mod lexer_tests {
 
-    use std::assert_eq;
+    use std::{assert_eq, borrow::Cow};
 
     use crate::{
         enums::{
-            arithmetic::Arithmetic, cmp::Cmp, keyword::Keyword, logicop::LogicOp,
-            token_type::TokenType,
+            arithmetic::Arithmetic, assign_op::AssignOp, bitop::Bitop, cmp::Cmp, keyword::Keyword,
+            logicop::LogicOp, token_type::TokenType,
         },
-        lexer::Lexer,
-        token::Token,
+        interner::Symbol,
+        lexer::{dialect::Dialect, error::LexErrorCode, incremental::TextEdit, Lexer},
+        source_map::SourceMap,
+        span::{FileId, Location},
+        token::{Token, Trivia, TriviaKind},
     };
 
-    const INPUT: &'static str = "=+(){},;";
-    const CODE: &'static str = r#"let five = 5;
+    const INPUT: &str = "=+(){},;";
+    const CODE: &str = r#"let five = 5;
         let ten = 10;
 
         let add = fn(x, y) {
@@ -34,7 +39,7 @@ mod lexer_tests {
         tokens.push((TokenType::COMMA, ","));
         tokens.push((TokenType::SEMICOLON, ";"));
 
-        let mut lexer = Lexer::new(INPUT.chars().collect());
+        let mut lexer = Lexer::new(INPUT);
 
         for (key, value) in tokens.into_iter() {
             let tok: Token = lexer.next_token();
@@ -87,7 +92,7 @@ mod lexer_tests {
         tokens.push((TokenType::SEMICOLON, ";"));
         tokens.push((TokenType::EOF, "\0"));
 
-        let mut lexer = Lexer::new(CODE.chars().collect());
+        let mut lexer = Lexer::new(CODE);
 
         for (key, value) in tokens.into_iter() {
             let tok: Token = lexer.next_token();
@@ -99,14 +104,14 @@ mod lexer_tests {
 
     #[test]
     fn test_token_number() {
-        let code: &'static str = r#"
+        let code: &str = r#"
             const i = 0;
             let j = 0;
 
             let result = i + j;
         "#;
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
 
         let mut tokens: Vec<(TokenType, &str, usize)> = Vec::new();
 
@@ -135,13 +140,13 @@ mod lexer_tests {
 
             assert_eq!(tok.t, key);
             assert_eq!(tok.value, value);
-            assert_eq!(tok.line.unwrap(), line);
+            assert_eq!(tok.span.start.line, line);
         }
     }
 
     #[test]
     fn test_control_tokens() {
-        let code: &'static str = r#"
+        let code: &str = r#"
             if true {
                 print(true);
             } else {
@@ -185,9 +190,7 @@ mod lexer_tests {
         tokens.push((TokenType::LBRACE, "{"));
         tokens.push((TokenType::IDENT, "print"));
         tokens.push((TokenType::LPAREN, "("));
-        tokens.push((TokenType::DQUOTE, "\""));
-        tokens.push((TokenType::IDENT, "hot"));
-        tokens.push((TokenType::DQUOTE, "\""));
+        tokens.push((TokenType::STRING, "hot"));
         tokens.push((TokenType::RPAREN, ")"));
         tokens.push((TokenType::SEMICOLON, ";"));
         tokens.push((TokenType::RBRACE, "}"));
@@ -195,9 +198,7 @@ mod lexer_tests {
         tokens.push((TokenType::LBRACE, "{"));
         tokens.push((TokenType::IDENT, "print"));
         tokens.push((TokenType::LPAREN, "("));
-        tokens.push((TokenType::DQUOTE, "\""));
-        tokens.push((TokenType::IDENT, "cold"));
-        tokens.push((TokenType::DQUOTE, "\""));
+        tokens.push((TokenType::STRING, "cold"));
         tokens.push((TokenType::RPAREN, ")"));
         tokens.push((TokenType::SEMICOLON, ";"));
         tokens.push((TokenType::RBRACE, "}"));
@@ -228,7 +229,7 @@ mod lexer_tests {
         tokens.push((TokenType::SEMICOLON, ";"));
         tokens.push((TokenType::KEYWORD(Keyword::END), "end"));
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
 
         for (key, value) in tokens {
             let token = lexer.next_token();
@@ -240,15 +241,14 @@ mod lexer_tests {
 
     #[test]
     fn test_peek_next_token_in_code() {
-        let code: &'static str = r#"
-            ++--**//&&||....=::
+        let code: &str = r#"
+            ++--**&&||....=::
         "#;
         let mut tokens: Vec<(TokenType, &str)> = Vec::new();
 
         tokens.push((TokenType::ARITHMETIC(Arithmetic::INC), "++"));
         tokens.push((TokenType::ARITHMETIC(Arithmetic::DEC), "--"));
         tokens.push((TokenType::ARITHMETIC(Arithmetic::POW), "**"));
-        tokens.push((TokenType::ARITHMETIC(Arithmetic::FDIV), "//"));
 
         tokens.push((TokenType::LOGICOP(LogicOp::AND), "&&"));
         tokens.push((TokenType::LOGICOP(LogicOp::OR), "||"));
@@ -257,7 +257,7 @@ mod lexer_tests {
         tokens.push((TokenType::IRANGE, "..="));
         tokens.push((TokenType::SCOPE, "::"));
 
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
 
         for (key, value) in tokens {
             let token = lexer.next_token();
@@ -266,4 +266,1235 @@ mod lexer_tests {
             assert_eq!(token.value, value);
         }
     }
+
+    #[test]
+    fn test_multi_character_operators() {
+        let code = "+= -= *= /= %= **= &= |= ^= <<= >>= << >> -> => ** * <= >= == != ! ~";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::ASSIGNOP(AssignOp::PLUS), "+="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::MINUS), "-="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::MUL), "*="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::DIV), "/="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::MOD), "%="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::POW), "**="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::AND), "&="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::OR), "|="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::XOR), "^="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::SHL), "<<="));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::SHR), ">>="));
+        tokens.push((TokenType::BITOP(Bitop::SHL), "<<"));
+        tokens.push((TokenType::BITOP(Bitop::SHR), ">>"));
+        tokens.push((TokenType::ARROW, "->"));
+        tokens.push((TokenType::FATARROW, "=>"));
+        tokens.push((TokenType::ARITHMETIC(Arithmetic::POW), "**"));
+        tokens.push((TokenType::ARITHMETIC(Arithmetic::MUL), "*"));
+        tokens.push((TokenType::CMP(Cmp::LE), "<="));
+        tokens.push((TokenType::CMP(Cmp::GE), ">="));
+        tokens.push((TokenType::CMP(Cmp::EQUAL), "=="));
+        tokens.push((TokenType::CMP(Cmp::NEQUAL), "!="));
+        tokens.push((TokenType::LOGICOP(LogicOp::NOT), "!"));
+        tokens.push((TokenType::BITOP(Bitop::NOT), "~"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+    }
+
+    #[test]
+    fn test_longest_match_operators() {
+        let code = "y -= 1; fn add(a: i32) -> i32 {} x=>y a<<=b>>c a&&=b";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::IDENT, "y"));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::MINUS), "-="));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::KEYWORD(Keyword::FUNCTION), "fn"));
+        tokens.push((TokenType::IDENT, "add"));
+        tokens.push((TokenType::LPAREN, "("));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::COLON, ":"));
+        tokens.push((TokenType::IDENT, "i32"));
+        tokens.push((TokenType::RPAREN, ")"));
+        tokens.push((TokenType::ARROW, "->"));
+        tokens.push((TokenType::IDENT, "i32"));
+        tokens.push((TokenType::LBRACE, "{"));
+        tokens.push((TokenType::RBRACE, "}"));
+        tokens.push((TokenType::IDENT, "x"));
+        tokens.push((TokenType::FATARROW, "=>"));
+        tokens.push((TokenType::IDENT, "y"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::ASSIGNOP(AssignOp::SHL), "<<="));
+        tokens.push((TokenType::IDENT, "b"));
+        tokens.push((TokenType::BITOP(Bitop::SHR), ">>"));
+        tokens.push((TokenType::IDENT, "c"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::LOGICOP(LogicOp::AND), "&&"));
+        tokens.push((TokenType::ASSIGN, "="));
+        tokens.push((TokenType::IDENT, "b"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+    }
+
+    #[test]
+    fn test_punctuation() {
+        let code = "#[test] let a = [1, 2]; a[0]; read()?; @x $y #![no_std]";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::HASH, "#"));
+        tokens.push((TokenType::LBRACKET, "["));
+        tokens.push((TokenType::IDENT, "test"));
+        tokens.push((TokenType::RBRACKET, "]"));
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::ASSIGN, "="));
+        tokens.push((TokenType::LBRACKET, "["));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::COMMA, ","));
+        tokens.push((TokenType::INT, "2"));
+        tokens.push((TokenType::RBRACKET, "]"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::LBRACKET, "["));
+        tokens.push((TokenType::INT, "0"));
+        tokens.push((TokenType::RBRACKET, "]"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::IDENT, "read"));
+        tokens.push((TokenType::LPAREN, "("));
+        tokens.push((TokenType::RPAREN, ")"));
+        tokens.push((TokenType::QUESTION, "?"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::AT, "@"));
+        tokens.push((TokenType::IDENT, "x"));
+        tokens.push((TokenType::DOLLAR, "$"));
+        tokens.push((TokenType::IDENT, "y"));
+        tokens.push((TokenType::HASH, "#"));
+        tokens.push((TokenType::LOGICOP(LogicOp::NOT), "!"));
+        tokens.push((TokenType::LBRACKET, "["));
+        tokens.push((TokenType::IDENT, "no_std"));
+        tokens.push((TokenType::RBRACKET, "]"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+    }
+
+    #[test]
+    fn test_macro_bang() {
+        let code = "vec![1] a != b !a x! y /**/!z";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::IDENT, "vec"));
+        tokens.push((TokenType::BANG, "!"));
+        tokens.push((TokenType::LBRACKET, "["));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::RBRACKET, "]"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::CMP(Cmp::NEQUAL), "!="));
+        tokens.push((TokenType::IDENT, "b"));
+        tokens.push((TokenType::LOGICOP(LogicOp::NOT), "!"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::IDENT, "x"));
+        tokens.push((TokenType::BANG, "!"));
+        tokens.push((TokenType::IDENT, "y"));
+        tokens.push((TokenType::LOGICOP(LogicOp::NOT), "!"));
+        tokens.push((TokenType::IDENT, "z"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+    }
+
+    #[test]
+    fn test_string_literals() {
+        let code = r#"println!("Even number: {}", i); "tab\there" "line\nbreak" "quote \" and \\ slash" "\u{1F600}\u{e9}""#;
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::IDENT, "println"));
+        tokens.push((TokenType::BANG, "!"));
+        tokens.push((TokenType::LPAREN, "("));
+        tokens.push((TokenType::STRING, "Even number: {}"));
+        tokens.push((TokenType::COMMA, ","));
+        tokens.push((TokenType::IDENT, "i"));
+        tokens.push((TokenType::RPAREN, ")"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::STRING, "tab\there"));
+        tokens.push((TokenType::STRING, "line\nbreak"));
+        tokens.push((TokenType::STRING, "quote \" and \\ slash"));
+        tokens.push((TokenType::STRING, "\u{1F600}\u{e9}"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        let code = "let s = \"never closed;\nlet t = 1;";
+        let mut lexer = Lexer::new(code);
+
+        let mut token = lexer.next_token();
+        while token.t != TokenType::STRING {
+            token = lexer.next_token();
+        }
+
+        assert_eq!(token.value, "never closed;\nlet t = 1;");
+        assert_eq!(lexer.next_token().t, TokenType::EOF);
+
+        let errors = lexer.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), &LexErrorCode::UnterminatedString);
+        assert_eq!(errors[0].line(), 1);
+        assert_eq!(errors[0].column(), 9);
+    }
+
+    #[test]
+    fn test_invalid_escapes() {
+        let code = r#""bad \q escape" "\u{D800}" "\u{110000}" "\u{1234}" "ok""#;
+        let mut lexer = Lexer::new(code);
+
+        let mut values = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token.t == TokenType::EOF {
+                break;
+            }
+
+            assert_eq!(token.t, TokenType::STRING);
+            values.push(token.value);
+        }
+
+        assert_eq!(values, vec!["bad  escape", "", "", "\u{1234}", "ok"]);
+
+        let errors = lexer.errors();
+        assert_eq!(errors.len(), 3);
+        assert!(errors
+            .iter()
+            .all(|e| e.code() == &LexErrorCode::InvalidEscape));
+        assert_eq!(errors[0].column(), 6);
+        assert_eq!(errors[0].message(), "Invalid escape sequence: '\\q'");
+    }
+
+    #[test]
+    fn test_float_and_suffixed_numbers() {
+        let code = "const PI: f32 = 3.14; 0..10 1e-9 6.02E+23 2.5f32 10u8 7i64 3f64 1.max";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::KEYWORD(Keyword::CONST), "const"));
+        tokens.push((TokenType::IDENT, "PI"));
+        tokens.push((TokenType::COLON, ":"));
+        tokens.push((TokenType::IDENT, "f32"));
+        tokens.push((TokenType::ASSIGN, "="));
+        tokens.push((TokenType::FLOAT, "3.14"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::INT, "0"));
+        tokens.push((TokenType::RANGE, ".."));
+        tokens.push((TokenType::INT, "10"));
+        tokens.push((TokenType::FLOAT, "1e-9"));
+        tokens.push((TokenType::FLOAT, "6.02E+23"));
+        tokens.push((TokenType::FLOAT, "2.5f32"));
+        tokens.push((TokenType::INT, "10u8"));
+        tokens.push((TokenType::INT, "7i64"));
+        tokens.push((TokenType::FLOAT, "3f64"));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::DOT, "."));
+        tokens.push((TokenType::IDENT, "max"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_malformed_number_suffixes() {
+        let code = "2.5u8 10abc";
+        let mut lexer = Lexer::new(code);
+
+        let float = lexer.next_token();
+        assert_eq!(float.t, TokenType::FLOAT);
+        assert_eq!(float.value, "2.5u8");
+
+        let int = lexer.next_token();
+        assert_eq!(int.t, TokenType::INT);
+        assert_eq!(int.value, "10abc");
+        assert_eq!(int.span.start.column, 7);
+
+        let errors = lexer.errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .all(|e| e.code() == &LexErrorCode::MalformedNumber));
+        assert_eq!(errors[1].column(), 7);
+        assert_eq!(
+            errors[1].message(),
+            "Malformed number '10abc': unknown suffix 'abc'"
+        );
+    }
+
+    #[test]
+    fn test_radix_numbers_and_separators() {
+        let code = "0xFF 0o755 0b1010_0001 1_000_000 0xFFu8 0x1f32 1_000.5 007";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::INT, "255"));
+        tokens.push((TokenType::INT, "493"));
+        tokens.push((TokenType::INT, "161"));
+        tokens.push((TokenType::INT, "1000000"));
+        tokens.push((TokenType::INT, "255u8"));
+        tokens.push((TokenType::INT, "7986"));
+        tokens.push((TokenType::FLOAT, "1000.5"));
+        tokens.push((TokenType::INT, "7"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_digits_and_overflow() {
+        let code = "0b102 0o8 0x 256u8 128i8 2147483649 4294967295u32";
+        let mut lexer = Lexer::new(code);
+
+        let mut values = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token.t == TokenType::EOF {
+                break;
+            }
+
+            assert_eq!(token.t, TokenType::INT);
+            values.push(token.value);
+        }
+
+        assert_eq!(
+            values,
+            vec![
+                "0b102",
+                "0o8",
+                "0x",
+                "256u8",
+                "128i8",
+                "2147483649",
+                "4294967295u32"
+            ]
+        );
+
+        let codes = lexer
+            .errors()
+            .iter()
+            .map(|e| e.code().clone())
+            .collect::<Vec<LexErrorCode>>();
+
+        assert_eq!(
+            codes,
+            vec![
+                LexErrorCode::InvalidDigit,
+                LexErrorCode::InvalidDigit,
+                LexErrorCode::MalformedNumber,
+                LexErrorCode::IntegerOverflow,
+                LexErrorCode::IntegerOverflow,
+            ]
+        );
+        assert_eq!(
+            lexer.errors()[0].message(),
+            "Invalid digit '2' in a base 2 literal"
+        );
+        assert_eq!(
+            lexer.errors()[3].message(),
+            "Integer literal '256u8' does not fit in u8"
+        );
+    }
+
+    #[test]
+    fn test_comments() {
+        let code = r#"// a line comment
+            let x = 5; // trailing comment
+            /* a block
+               /* nested */ comment */
+            //! Inner doc comment
+            /// Adds two numbers.
+            //// not a doc comment
+            fn add(a, b) { a / b }"#;
+        let mut tokens: Vec<(TokenType, &str, usize)> = Vec::new();
+
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let", 2));
+        tokens.push((TokenType::IDENT, "x", 2));
+        tokens.push((TokenType::ASSIGN, "=", 2));
+        tokens.push((TokenType::INT, "5", 2));
+        tokens.push((TokenType::SEMICOLON, ";", 2));
+        tokens.push((TokenType::DOC_COMMENT, "//! Inner doc comment", 5));
+        tokens.push((TokenType::DOC_COMMENT, "/// Adds two numbers.", 6));
+        tokens.push((TokenType::KEYWORD(Keyword::FUNCTION), "fn", 8));
+        tokens.push((TokenType::IDENT, "add", 8));
+        tokens.push((TokenType::LPAREN, "(", 8));
+        tokens.push((TokenType::IDENT, "a", 8));
+        tokens.push((TokenType::COMMA, ",", 8));
+        tokens.push((TokenType::IDENT, "b", 8));
+        tokens.push((TokenType::RPAREN, ")", 8));
+        tokens.push((TokenType::LBRACE, "{", 8));
+        tokens.push((TokenType::IDENT, "a", 8));
+        tokens.push((TokenType::ARITHMETIC(Arithmetic::DIV), "/", 8));
+        tokens.push((TokenType::IDENT, "b", 8));
+        tokens.push((TokenType::RBRACE, "}", 8));
+        tokens.push((TokenType::EOF, "\0", 8));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value, line) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+            assert_eq!(token.span.start.line, line);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_unterminated_block_comment() {
+        let code = "let x = 1;\n/* outer /* inner */ never closed\nlet y = 2;";
+        let mut lexer = Lexer::new(code);
+
+        let mut token = lexer.next_token();
+        while token.t != TokenType::EOF {
+            token = lexer.next_token();
+        }
+
+        let errors = lexer.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), &LexErrorCode::UnterminatedComment);
+        assert_eq!(errors[0].line(), 2);
+        assert_eq!(errors[0].column(), 1);
+    }
+
+    #[test]
+    fn test_char_literals() {
+        let code = r#"'a' '\n' '\'' '"' '\u{1F600}' 'é' "'""#;
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::CHAR, "a"));
+        tokens.push((TokenType::CHAR, "\n"));
+        tokens.push((TokenType::CHAR, "'"));
+        tokens.push((TokenType::CHAR, "\""));
+        tokens.push((TokenType::CHAR, "\u{1F600}"));
+        tokens.push((TokenType::CHAR, "é"));
+        tokens.push((TokenType::STRING, "'"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_char_literals() {
+        let code = "'' 'ab' '\\q' 'x\nlet";
+        let mut lexer = Lexer::new(code);
+
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token.t == TokenType::EOF {
+                break;
+            }
+
+            tokens.push((token.t, token.value));
+        }
+
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenType::CHAR, "".to_owned()),
+                (TokenType::CHAR, "ab".to_owned()),
+                (TokenType::CHAR, "".to_owned()),
+                (TokenType::CHAR, "x".to_owned()),
+                (TokenType::KEYWORD(Keyword::LET), "let".to_owned()),
+            ]
+        );
+
+        let errors = lexer.errors();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0].code(), &LexErrorCode::InvalidCharLiteral);
+        assert_eq!(errors[0].message(), "Empty character literal");
+        assert_eq!(errors[1].code(), &LexErrorCode::InvalidCharLiteral);
+        assert_eq!(errors[2].code(), &LexErrorCode::InvalidEscape);
+        assert_eq!(errors[3].code(), &LexErrorCode::UnterminatedChar);
+    }
+
+    #[test]
+    fn test_token_spans() {
+        let code = "let x = \"ü\";\n  42;";
+        let spans = [
+            (TokenType::KEYWORD(Keyword::LET), (1, 1), (1, 4), 0..3),
+            (TokenType::IDENT, (1, 5), (1, 6), 4..5),
+            (TokenType::ASSIGN, (1, 7), (1, 8), 6..7),
+            (TokenType::STRING, (1, 9), (1, 12), 8..12),
+            (TokenType::SEMICOLON, (1, 12), (1, 13), 12..13),
+            (TokenType::INT, (2, 3), (2, 5), 16..18),
+            (TokenType::SEMICOLON, (2, 5), (2, 6), 18..19),
+            (TokenType::EOF, (2, 6), (2, 6), 19..19),
+        ];
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, start, end, byte_range) in spans {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.span.start, Location::new(start.0, start.1));
+            assert_eq!(token.span.end, Location::new(end.0, end.1));
+            assert_eq!(token.span.byte_range, byte_range);
+        }
+    }
+
+    #[test]
+    fn test_lexer_for_file() {
+        let mut source_map = SourceMap::new();
+        source_map.add_file("data/add.zpp", "let five = 5;");
+        let file_id = source_map.add_file("<repl:1>", "\"unterminated");
+
+        let mut lexer = Lexer::for_file(&source_map, file_id);
+        let token = lexer.next_token();
+
+        assert_eq!(file_id, FileId(1));
+        assert_eq!(token.t, TokenType::STRING);
+        assert_eq!(token.span.file_id, file_id);
+
+        let error = &lexer.errors()[0];
+        assert_eq!(
+            source_map.format_diagnostic(error.message(), error.span()),
+            "Unterminated string literal\n --> <repl:1>:1:1\n\"unterminated\n^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_next_lexeme_borrows_input() {
+        let code = "let café = \"a\\tb\" + 0x1F;";
+
+        let mut lexemes: Vec<(TokenType, &str, &str, bool)> = Vec::new();
+        lexemes.push((TokenType::KEYWORD(Keyword::LET), "let", "let", true));
+        lexemes.push((TokenType::IDENT, "café", "café", true));
+        lexemes.push((TokenType::ASSIGN, "=", "=", true));
+        lexemes.push((TokenType::STRING, "\"a\\tb\"", "a\tb", false));
+        lexemes.push((TokenType::ARITHMETIC(Arithmetic::PLUS), "+", "+", true));
+        lexemes.push((TokenType::INT, "0x1F", "31", false));
+        lexemes.push((TokenType::SEMICOLON, ";", ";", true));
+        lexemes.push((TokenType::EOF, "", "\0", true));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, text, value, borrowed) in lexemes {
+            let lexeme = lexer.next_lexeme();
+
+            assert_eq!(lexeme.t, key);
+            assert_eq!(lexeme.text, text);
+            assert_eq!(lexeme.value, value);
+            assert_eq!(matches!(lexeme.value, Cow::Borrowed(_)), borrowed);
+            assert_eq!(&code[lexeme.span.byte_range], text);
+        }
+    }
+
+    #[test]
+    fn test_lexer_iterator() {
+        let tokens: Vec<Token> = Lexer::new("let x = 5;").collect();
+
+        let types: Vec<TokenType> = tokens.iter().map(|token| token.t).collect();
+        assert_eq!(
+            types,
+            vec![
+                TokenType::KEYWORD(Keyword::LET),
+                TokenType::IDENT,
+                TokenType::ASSIGN,
+                TokenType::INT,
+                TokenType::SEMICOLON,
+                TokenType::EOF,
+            ]
+        );
+
+        let mut lexer = Lexer::new("");
+        assert_eq!(lexer.next().map(|token| token.t), Some(TokenType::EOF));
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn test_token_stream_matches_lexer() {
+        // Large enough to need several refills, with tokens spanning lines across them.
+        let chunk = format!(
+            "{}\n/* a block\n comment */ let s = \"two\nlines\";\n/// doc é\nlet t = `a ${{ {{x}} }}\nb`;\n",
+            include_str!("../../data/rust_like.zpp")
+        );
+        let code = chunk.repeat(100) + "let unterminated = \"oops";
+
+        let mut lexer = Lexer::new(&code);
+        let expected: Vec<Token> = lexer.by_ref().collect();
+
+        let mut stream = Lexer::from_reader(code.as_bytes()).with_file_id(FileId(3));
+        let tokens: Vec<Token> = stream.by_ref().collect();
+
+        assert_eq!(tokens.len(), expected.len());
+        for (token, expected) in tokens.iter().zip(expected.iter()) {
+            assert_eq!(token.t, expected.t);
+            assert_eq!(token.value, expected.value);
+            assert_eq!(token.span.start, expected.span.start);
+            assert_eq!(token.span.end, expected.span.end);
+            assert_eq!(token.span.byte_range, expected.span.byte_range);
+            assert_eq!(token.span.file_id, FileId(3));
+        }
+
+        assert!(stream.io_error().is_none());
+        assert_eq!(stream.errors().len(), 1);
+        assert_eq!(stream.errors()[0].code(), lexer.errors()[0].code());
+        assert_eq!(
+            stream.errors()[0].span().start,
+            lexer.errors()[0].span().start
+        );
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_unicode_identifiers() {
+        // "cafe\u{301}" is "café" spelled with a combining accent, which NFC composes.
+        let code = "let été = 1; let 名前 = été; cafe\u{301} _x1 x\u{2070} 1abc";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let"));
+        tokens.push((TokenType::IDENT, "été"));
+        tokens.push((TokenType::ASSIGN, "="));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let"));
+        tokens.push((TokenType::IDENT, "名前"));
+        tokens.push((TokenType::ASSIGN, "="));
+        tokens.push((TokenType::IDENT, "été"));
+        tokens.push((TokenType::SEMICOLON, ";"));
+        tokens.push((TokenType::IDENT, "caf\u{e9}"));
+        tokens.push((TokenType::IDENT, "_x1"));
+        // U+2070 (superscript zero) is not XID_Continue.
+        tokens.push((TokenType::IDENT, "x"));
+        tokens.push((TokenType::ILLEGAL, "\u{2070}"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert_eq!(lexer.errors().len(), 1);
+        assert_eq!(lexer.errors()[0].code(), &LexErrorCode::UnknownCharacter);
+        assert!(lexer.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_confusable_identifiers() {
+        // The 'а' in the first identifier is Cyrillic.
+        let code = "let p\u{430}ypal = 1; let paypal = 2; let x名 = 3;";
+        let mut lexer = Lexer::new(code);
+
+        while lexer.next_token().t != TokenType::EOF {}
+
+        assert!(lexer.errors().is_empty());
+        assert_eq!(lexer.warnings().len(), 1);
+
+        let warning = &lexer.warnings()[0];
+        assert_eq!(warning.code(), &LexErrorCode::ConfusableIdentifier);
+        assert_eq!(warning.span().start, Location::new(1, 5));
+        assert_eq!(warning.span().end, Location::new(1, 11));
+    }
+
+    #[test]
+    fn test_template_strings() {
+        let code = "`Hello ${name}, you are ${age + 1}` `` `${ {a} } \\` \\${ ${ `in ${x}` }`";
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::TEMPLATE_STRING, "Hello "));
+        tokens.push((TokenType::INTERP_START, "${"));
+        tokens.push((TokenType::IDENT, "name"));
+        tokens.push((TokenType::INTERP_END, "}"));
+        tokens.push((TokenType::TEMPLATE_STRING, ", you are "));
+        tokens.push((TokenType::INTERP_START, "${"));
+        tokens.push((TokenType::IDENT, "age"));
+        tokens.push((TokenType::ARITHMETIC(Arithmetic::PLUS), "+"));
+        tokens.push((TokenType::INT, "1"));
+        tokens.push((TokenType::INTERP_END, "}"));
+        tokens.push((TokenType::BACKTICK, "`"));
+
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::BACKTICK, "`"));
+
+        // Braces inside an interpolation, escapes and a nested template.
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::INTERP_START, "${"));
+        tokens.push((TokenType::LBRACE, "{"));
+        tokens.push((TokenType::IDENT, "a"));
+        tokens.push((TokenType::RBRACE, "}"));
+        tokens.push((TokenType::INTERP_END, "}"));
+        tokens.push((TokenType::TEMPLATE_STRING, " ` ${ "));
+        tokens.push((TokenType::INTERP_START, "${"));
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::TEMPLATE_STRING, "in "));
+        tokens.push((TokenType::INTERP_START, "${"));
+        tokens.push((TokenType::IDENT, "x"));
+        tokens.push((TokenType::INTERP_END, "}"));
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::INTERP_END, "}"));
+        tokens.push((TokenType::BACKTICK, "`"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_unterminated_template_string() {
+        let code = "let s = `a ${ `b";
+        let mut lexer = Lexer::new(code);
+
+        let types: Vec<TokenType> = lexer.by_ref().map(|token| token.t).collect();
+
+        assert_eq!(types[types.len() - 2], TokenType::TEMPLATE_STRING);
+        assert_eq!(types[types.len() - 1], TokenType::EOF);
+        assert_eq!(lexer.errors().len(), 1);
+
+        let error = &lexer.errors()[0];
+        assert_eq!(error.code(), &LexErrorCode::UnterminatedTemplate);
+        assert_eq!(error.span().start, Location::new(1, 15));
+        assert_eq!(error.span().byte_range, 14..16);
+    }
+
+    #[test]
+    fn test_raw_strings() {
+        let code = r####"r"C:\path\n" r#"say "hi""# r##"a "# b"## r"" r#"
+"# raw"####;
+        let mut tokens: Vec<(TokenType, &str)> = Vec::new();
+
+        tokens.push((TokenType::STRING, r"C:\path\n"));
+        tokens.push((TokenType::STRING, r#"say "hi""#));
+        tokens.push((TokenType::STRING, r##"a "# b"##));
+        tokens.push((TokenType::STRING, ""));
+        tokens.push((TokenType::STRING, "\n"));
+        tokens.push((TokenType::IDENT, "raw"));
+        tokens.push((TokenType::EOF, "\0"));
+
+        let mut lexer = Lexer::new(code);
+
+        for (key, value) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_unterminated_raw_string() {
+        let code = r###"let s = r##"never "closed"#;"###;
+        let mut lexer = Lexer::new(code);
+
+        let types: Vec<TokenType> = lexer.by_ref().map(|token| token.t).collect();
+
+        assert_eq!(
+            types,
+            vec![
+                TokenType::KEYWORD(Keyword::LET),
+                TokenType::IDENT,
+                TokenType::ASSIGN,
+                TokenType::STRING,
+                TokenType::EOF,
+            ]
+        );
+        assert_eq!(lexer.errors().len(), 1);
+        assert_eq!(lexer.errors()[0].code(), &LexErrorCode::UnterminatedString);
+    }
+
+    #[test]
+    fn test_multiline_strings() {
+        let code = "let sql = \"\"\"
+            SELECT *
+              FROM users\\t
+            WHERE id = 1
+            \"\"\";
+let inline = \"\"\"one \"quoted\" line\"\"\";";
+        let mut lexer = Lexer::new(code);
+
+        let mut tokens: Vec<(TokenType, &str, usize)> = Vec::new();
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let", 1));
+        tokens.push((TokenType::IDENT, "sql", 1));
+        tokens.push((TokenType::ASSIGN, "=", 1));
+        tokens.push((
+            TokenType::STRING,
+            "SELECT *\n  FROM users\t\nWHERE id = 1",
+            1,
+        ));
+        tokens.push((TokenType::SEMICOLON, ";", 5));
+        tokens.push((TokenType::KEYWORD(Keyword::LET), "let", 6));
+        tokens.push((TokenType::IDENT, "inline", 6));
+        tokens.push((TokenType::ASSIGN, "=", 6));
+        tokens.push((TokenType::STRING, "one \"quoted\" line", 6));
+        tokens.push((TokenType::SEMICOLON, ";", 6));
+
+        for (key, value, line) in tokens {
+            let token = lexer.next_token();
+
+            assert_eq!(token.t, key);
+            assert_eq!(token.value, value);
+            assert_eq!(token.span.start.line, line);
+        }
+
+        assert!(lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn test_multiline_string_keeps_relative_indentation() {
+        let code =
+            "\"\"\"\r\n    {\r\n      \"a\": 1\r\n\r\n    }\r\n  \"\"\" x \"\"\"unterminated";
+        let mut lexer = Lexer::new(code);
+
+        let token = lexer.next_token();
+        assert_eq!(token.t, TokenType::STRING);
+        assert_eq!(token.value, "{\n  \"a\": 1\n\n}");
+        assert_eq!(token.span.end, Location::new(6, 6));
+
+        assert_eq!(lexer.next_token().t, TokenType::IDENT);
+        assert_eq!(lexer.next_token().t, TokenType::STRING);
+        assert_eq!(lexer.next_token().t, TokenType::EOF);
+        assert_eq!(lexer.errors().len(), 1);
+        assert_eq!(lexer.errors()[0].code(), &LexErrorCode::UnterminatedString);
+    }
+
+    #[test]
+    fn test_errors_are_collected_and_lexing_continues() {
+        let code = "let a = 1 § 2;\nlet b = \"bad \\q\";\nlet c = 0b102;\nlet d = \u{7};\n\"open";
+        let mut lexer = Lexer::new(code);
+
+        let tokens: Vec<Token> = lexer.by_ref().collect();
+
+        // Every statement is still lexed, the bad characters becoming ILLEGAL tokens.
+        let semicolons = tokens
+            .iter()
+            .filter(|token| token.t == TokenType::SEMICOLON)
+            .count();
+        assert_eq!(semicolons, 4);
+        assert_eq!(tokens[4].t, TokenType::ILLEGAL);
+        assert_eq!(tokens[5].t, TokenType::INT);
+        assert_eq!(tokens.last().unwrap().t, TokenType::EOF);
+
+        let errors: Vec<(LexErrorCode, usize, usize)> = lexer
+            .errors()
+            .iter()
+            .map(|error| (error.code().clone(), error.line(), error.column()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (LexErrorCode::UnknownCharacter, 1, 11),
+                (LexErrorCode::InvalidEscape, 2, 14),
+                (LexErrorCode::InvalidDigit, 3, 9),
+                (LexErrorCode::UnknownCharacter, 4, 9),
+                (LexErrorCode::UnterminatedString, 5, 1),
+            ]
+        );
+        assert_eq!(
+            lexer.errors()[3].message(),
+            "Unknown character '\\u{7}' (U+0007)"
+        );
+    }
+
+    fn assert_round_trip(code: &str) {
+        let tokens: Vec<Token> = Lexer::new(code).with_trivia().collect();
+
+        let source: String = tokens.iter().map(Token::to_source).collect();
+
+        assert_eq!(source, code);
+    }
+
+    #[test]
+    fn test_trivia_round_trip() {
+        assert_round_trip(include_str!("../../data/add.zpp"));
+        assert_round_trip(include_str!("../../data/rust_like.zpp"));
+        assert_round_trip("");
+        assert_round_trip("  /* only */ // trivia\r\n");
+        assert_round_trip("let s = `a ${ b } c`; // end\r\n/// doc\nx /* a\nb */ y");
+        assert_round_trip("\"unterminated");
+    }
+
+    #[test]
+    fn test_leading_and_trailing_trivia() {
+        let code = "let x = 1; // one\n\n  /* two */ y";
+        let mut lexer = Lexer::new(code).with_trivia();
+
+        let tokens: Vec<Token> = lexer.by_ref().collect();
+        let semicolon = tokens[4].trivia.as_ref().unwrap();
+        let y = tokens[5].trivia.as_ref().unwrap();
+
+        assert_eq!(semicolon.text, ";");
+        assert!(semicolon.leading.is_empty());
+        assert_eq!(
+            semicolon.trailing,
+            vec![
+                Trivia {
+                    kind: TriviaKind::WHITESPACE,
+                    text: " ".to_owned(),
+                },
+                Trivia {
+                    kind: TriviaKind::LINE_COMMENT,
+                    text: "// one".to_owned(),
+                },
+            ]
+        );
+
+        let leading: Vec<(TriviaKind, &str)> = y
+            .leading
+            .iter()
+            .map(|trivia| (trivia.kind, trivia.text.as_str()))
+            .collect();
+        assert_eq!(
+            leading,
+            vec![
+                (TriviaKind::WHITESPACE, "\n\n  "),
+                (TriviaKind::BLOCK_COMMENT, "/* two */"),
+                (TriviaKind::WHITESPACE, " "),
+            ]
+        );
+        assert_eq!(y.text, "y");
+
+        // Tokens only carry trivia in trivia mode.
+        assert!(Lexer::new(code).next_token().trivia.is_none());
+    }
+
+    fn assert_relex(code: &str, edit: TextEdit) {
+        let tokens: Vec<Token> = Lexer::new(code).collect();
+
+        let mut edited = code.to_owned();
+        edited.replace_range(edit.range.clone(), &edit.replacement);
+
+        let expected: Vec<Token> = Lexer::new(&edited).collect();
+        assert_eq!(
+            Lexer::new(&edited).relex(&tokens, &edit),
+            expected,
+            "{:?} applied to {:?}",
+            edit,
+            code
+        );
+    }
+
+    #[test]
+    fn test_relex_matches_full_lex() {
+        let code = "let x = 1..5; // one\nlet y = x<<2;\n/* two */ z! r#\"raw\"# `a ${b} c`";
+
+        assert_relex(code, TextEdit::new(4..5, "value"));
+        assert_relex(code, TextEdit::new(9..10, "2"));
+        assert_relex(code, TextEdit::new(21..21, "\n\n"));
+        assert_relex(code, TextEdit::new(0..code.len(), ""));
+        assert_relex(code, TextEdit::new(code.len()..code.len(), " tail"));
+    }
+
+    #[test]
+    fn test_relex_edits_that_change_distant_tokens() {
+        let code = "a 1..5 b\nc /* d */ e \"f\" g\n`h ${i} j` k";
+
+        // Turns the range into a float.
+        assert_relex(code, TextEdit::new(3..4, "2"));
+        // Opens a string that swallows the comment.
+        assert_relex(code, TextEdit::new(11..11, "\""));
+        // Closes the comment early and opens one that never ends.
+        assert_relex(code, TextEdit::new(14..14, "*/"));
+        assert_relex(code, TextEdit::new(16..18, ""));
+        // Ends the template early and opens another one.
+        assert_relex(code, TextEdit::new(31..31, "`"));
+        // Makes the identifier before the shift a raw string.
+        assert_relex("r #\"x\"# y", TextEdit::new(1..2, ""));
+    }
+
+    #[test]
+    fn test_relex_every_position() {
+        let code = include_str!("../../data/rust_like.zpp");
+        let edits = [
+            "", "x", " ", "\n", "\"", "/*", "*/", "`", "${", "}", "1.", "=", "'",
+        ];
+
+        for (position, _) in code.char_indices().step_by(3) {
+            for replacement in edits {
+                assert_relex(code, TextEdit::new(position..position, replacement));
+
+                let end = code[position..]
+                    .char_indices()
+                    .nth(2)
+                    .map_or(code.len(), |(i, _)| position + i);
+                assert_relex(code, TextEdit::new(position..end, replacement));
+            }
+        }
+    }
+
+    #[test]
+    fn test_identifiers_are_interned() {
+        let tokens: Vec<Token> =
+            Lexer::new("let x = y + x; let e\u{301}t\u{e9} = \"x\";").collect();
+
+        assert_eq!(tokens[1].symbol, Some(Symbol::intern("x")));
+        assert_eq!(tokens[3].symbol, Some(Symbol::intern("y")));
+        assert_eq!(tokens[5].symbol, tokens[1].symbol);
+        // Identifiers are interned once normalised.
+        assert_eq!(tokens[8].symbol, Some(Symbol::intern("\u{e9}t\u{e9}")));
+
+        // Only identifiers get a symbol.
+        assert_eq!(tokens[0].symbol, None);
+        assert_eq!(tokens[10].symbol, None);
+    }
+
+    fn lex_types(code: &str, dialect: Dialect) -> Vec<TokenType> {
+        Lexer::new(code)
+            .with_dialect(dialect)
+            .map(|token| token.t)
+            .collect()
+    }
+
+    #[test]
+    fn test_dialects_lex_their_samples() {
+        let samples = [
+            (Dialect::RUST_LIKE, include_str!("../../data/rust_like.zpp")),
+            (Dialect::MONKEY_LIKE, include_str!("../../data/add.zpp")),
+            (
+                Dialect::LUA_LIKE,
+                "local function add(x, y)\n  return x + y\nend\nwhile x do x = x - 1 end",
+            ),
+        ];
+
+        for (dialect, code) in samples {
+            let mut lexer = Lexer::new(code).with_dialect(dialect);
+            let tokens: Vec<Token> = lexer.by_ref().collect();
+
+            assert!(lexer.errors().is_empty(), "{:?}", lexer.errors());
+            assert!(tokens.iter().all(|token| token.t != TokenType::ILLEGAL));
+        }
+
+        assert_eq!(
+            lex_types("local function f() end", Dialect::LUA_LIKE)[..3],
+            [
+                TokenType::KEYWORD(Keyword::LET),
+                TokenType::KEYWORD(Keyword::FUNCTION),
+                TokenType::IDENT,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dialect_rejects_foreign_keywords() {
+        let mut lexer = Lexer::new("var x = 1; do end; local y").with_dialect(Dialect::RUST_LIKE);
+        let tokens: Vec<Token> = lexer.by_ref().collect();
+
+        assert_eq!(tokens[0].t, TokenType::IDENT);
+        assert_eq!(tokens[5].t, TokenType::IDENT);
+
+        let errors: Vec<(LexErrorCode, usize)> = lexer
+            .errors()
+            .iter()
+            .map(|error| (error.code().clone(), error.column()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (LexErrorCode::ForeignKeyword, 1),
+                (LexErrorCode::ForeignKeyword, 12),
+                (LexErrorCode::ForeignKeyword, 15),
+            ]
+        );
+        assert_eq!(
+            lexer.errors()[1].message(),
+            "'do' is not a keyword of the Rust-like dialect"
+        );
+
+        // The default dialect keeps every keyword.
+        assert_eq!(
+            lex_types("do end", Dialect::default())[..2],
+            [
+                TokenType::KEYWORD(Keyword::DO),
+                TokenType::KEYWORD(Keyword::END),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dialect_operator_options() {
+        let code = "x += 1; f!(y); `a${b}`";
+
+        assert_eq!(
+            lex_types(code, Dialect::RUST_LIKE),
+            vec![
+                TokenType::IDENT,
+                TokenType::ASSIGNOP(AssignOp::PLUS),
+                TokenType::INT,
+                TokenType::SEMICOLON,
+                TokenType::IDENT,
+                TokenType::BANG,
+                TokenType::LPAREN,
+                TokenType::IDENT,
+                TokenType::RPAREN,
+                TokenType::SEMICOLON,
+                TokenType::BACKTICK,
+                TokenType::TEMPLATE_STRING,
+                TokenType::INTERP_START,
+                TokenType::IDENT,
+                TokenType::INTERP_END,
+                TokenType::BACKTICK,
+                TokenType::EOF,
+            ]
+        );
+        assert_eq!(
+            lex_types(code, Dialect::MONKEY_LIKE),
+            vec![
+                TokenType::IDENT,
+                TokenType::ARITHMETIC(Arithmetic::PLUS),
+                TokenType::ASSIGN,
+                TokenType::INT,
+                TokenType::SEMICOLON,
+                TokenType::IDENT,
+                TokenType::LOGICOP(LogicOp::NOT),
+                TokenType::LPAREN,
+                TokenType::IDENT,
+                TokenType::RPAREN,
+                TokenType::SEMICOLON,
+                TokenType::BACKTICK,
+                TokenType::IDENT,
+                TokenType::DOLLAR,
+                TokenType::LBRACE,
+                TokenType::IDENT,
+                TokenType::RBRACE,
+                TokenType::BACKTICK,
+                TokenType::EOF,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relex_keeps_the_dialect() {
+        let code = "x = `a` + y";
+        let tokens: Vec<Token> = Lexer::new(code)
+            .with_dialect(Dialect::MONKEY_LIKE)
+            .collect();
+
+        let edit = TextEdit::new(6..6, " b");
+        let edited = "x = `a b` + y";
+        let expected: Vec<Token> = Lexer::new(edited)
+            .with_dialect(Dialect::MONKEY_LIKE)
+            .collect();
+
+        assert_eq!(
+            Lexer::new(edited)
+                .with_dialect(Dialect::MONKEY_LIKE)
+                .relex(&tokens, &edit),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_rust_like_keywords() {
+        let code =
+            "pub struct S; impl T for S { fn f(self) { loop { break; } } } use a as b; mod m; \
+                    let mut x = null; for i in xs { continue; } match x {} enum E {} trait T {}";
+
+        let keywords: Vec<Keyword> = lex_types(code, Dialect::RUST_LIKE)
+            .into_iter()
+            .filter_map(|t| match t {
+                TokenType::KEYWORD(keyword) => Some(keyword),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            keywords,
+            vec![
+                Keyword::PUB,
+                Keyword::STRUCT,
+                Keyword::IMPL,
+                Keyword::FOR,
+                Keyword::FUNCTION,
+                Keyword::SELF,
+                Keyword::LOOP,
+                Keyword::BREAK,
+                Keyword::USE,
+                Keyword::AS,
+                Keyword::MODULE,
+                Keyword::LET,
+                Keyword::MUT,
+                Keyword::NULL,
+                Keyword::FOR,
+                Keyword::IN,
+                Keyword::CONTINUE,
+                Keyword::MATCH,
+                Keyword::ENUM,
+                Keyword::TRAIT,
+            ]
+        );
+    }
 }
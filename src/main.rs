@@ -7,10 +7,13 @@ use repl::REPL;
 mod ast;
 mod cli;
 mod enums;
+mod interner;
 mod lexer;
 mod parser;
 mod queue;
 mod repl;
+mod source_map;
+mod span;
 mod token;
 mod traits;
 
@@ -1,6 +1,6 @@
 use std::{error::Error, fmt::Display};
 
-use crate::{enums::token_type::TokenType, token::Token};
+use crate::{enums::token_type::TokenType, lexer::error::LexError, span::Span, token::Token};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParserErrorCode {
@@ -11,6 +11,12 @@ pub enum ParserErrorCode {
     MissingSemicolon,
     UnexpectedCharacter,
     InvalidAssignmentTarget,
+    UnsupportedKeyword,
+    NonAssociativeOperator,
+    DanglingElse,
+    InvalidNumber,
+    InvalidChar,
+    Lexical,
 }
 
 impl Display for ParserErrorCode {
@@ -29,9 +35,13 @@ impl From<Box<dyn Error>> for ParserError {
 pub struct ParserError {
     code: ParserErrorCode,
     message: String,
-    token: Option<Token>,
+    // Boxed so `Result<_, ParserError>` stays small on the happy path.
+    token: Option<Box<Token>>,
+    span: Option<Box<Span>>,
     at: Option<usize>,
     to: Option<usize>,
+    // The lexer diagnostic behind a `Lexical` error.
+    lex_error: Option<Box<LexError>>,
 }
 
 impl ParserError {
@@ -45,9 +55,11 @@ impl ParserError {
         Self {
             code,
             message,
-            token,
+            span: token.as_ref().map(|t| Box::new(t.span.clone())),
+            token: token.map(Box::new),
             at,
             to,
+            lex_error: None,
         }
     }
 
@@ -72,8 +84,13 @@ impl ParserError {
         ParserErrorBuilder::new(ParserErrorCode::UnexpectedEOF, "Unexpected end of file").build()
     }
 
-    pub fn invalid_expression() -> Self {
-        ParserErrorBuilder::new(ParserErrorCode::InvalidExpression, "Invalid expression").build()
+    pub fn invalid_expression(token: Token) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::InvalidExpression,
+            format!("Expected an expression, found '{}'", token.value),
+        )
+        .with_token(token)
+        .build()
     }
 
     pub fn missing_semicolon() -> Self {
@@ -96,6 +113,61 @@ impl ParserError {
         .build()
     }
 
+    pub fn non_associative_operator(token: Token, previous: &str) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::NonAssociativeOperator,
+            format!(
+                "Operator '{}' cannot be chained with '{}', use parentheses",
+                token.value, previous
+            ),
+        )
+        .with_token(token)
+        .build()
+    }
+
+    pub fn dangling_else(token: Token) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::DanglingElse,
+            "'else' without a matching 'if'",
+        )
+        .with_token(token)
+        .build()
+    }
+
+    pub fn invalid_number(token: Token) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::InvalidNumber,
+            format!("Invalid number literal: '{}'", token.value),
+        )
+        .with_token(token)
+        .build()
+    }
+
+    pub fn invalid_char(token: Token) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::InvalidChar,
+            format!("Invalid char literal: '{}'", token.value),
+        )
+        .with_token(token)
+        .build()
+    }
+
+    pub fn lexical(error: LexError) -> Self {
+        ParserErrorBuilder::new(ParserErrorCode::Lexical, error.message())
+            .with_span(error.span().clone())
+            .with_lex_error(error)
+            .build()
+    }
+
+    pub fn unsupported_keyword(token: Token) -> Self {
+        ParserErrorBuilder::new(
+            ParserErrorCode::UnsupportedKeyword,
+            format!("Keyword '{}' is not supported yet", token.value),
+        )
+        .with_token(token)
+        .build()
+    }
+
 
     pub fn code(&self) -> &ParserErrorCode {
         &self.code
@@ -114,11 +186,19 @@ impl ParserError {
     }
 
     pub fn token(&self) -> Option<&Token> {
-        self.token.as_ref()
+        self.token.as_deref()
     }
 
     pub fn set_token(&mut self, token: Option<Token>) {
-        self.token = token;
+        self.token = token.map(Box::new);
+    }
+
+    pub fn span(&self) -> Option<&Span> {
+        self.span.as_deref()
+    }
+
+    pub fn set_span(&mut self, span: Option<Span>) {
+        self.span = span.map(Box::new);
     }
 
     pub fn at(&self) -> Option<usize> {
@@ -137,9 +217,19 @@ impl ParserError {
         self.to = to;
     }
 
+    pub fn lex_error(&self) -> Option<&LexError> {
+        self.lex_error.as_deref()
+    }
+
 }
 
-impl Error for ParserError {}
+impl Error for ParserError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.lex_error
+            .as_deref()
+            .map(|error| error as &(dyn Error + 'static))
+    }
+}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -151,8 +241,10 @@ pub struct ParserErrorBuilder {
     code: ParserErrorCode,
     message: String,
     token: Option<Token>,
+    span: Option<Span>,
     at: Option<usize>,
     to: Option<usize>,
+    lex_error: Option<LexError>,
 }
 
 impl ParserErrorBuilder {
@@ -161,19 +253,31 @@ impl ParserErrorBuilder {
             code,
             message: message.into(),
             token: None,
+            span: None,
             at: None,
             to: None,
+            lex_error: None,
         }
     }
 
     pub fn with_token(mut self, token: Token) -> Self {
-        if let Some(line) = token.line {
-            self.at = Some(line);
-        }
+        self.at = Some(token.span.start.line);
+        self.span = Some(token.span.clone());
         self.token = Some(token);
         self
     }
 
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn with_lex_error(mut self, error: LexError) -> Self {
+        self.at = Some(error.line());
+        self.lex_error = Some(error);
+        self
+    }
+
     pub fn with_range(mut self, at: usize, to: usize) -> Self {
         self.at = Some(at);
         self.to = Some(to);
@@ -184,18 +288,22 @@ impl ParserErrorBuilder {
         ParserError {
             code: self.code,
             message: self.message,
-            token: self.token,
+            token: self.token.map(Box::new),
+            span: self.span.map(Box::new),
             at: self.at,
             to: self.to,
+            lex_error: self.lex_error.map(Box::new),
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unnecessary_literal_unwrap)]
 mod tests {
     use std::println;
 
     use super::*;
+    use crate::span::{FileId, Location};
 
     type TestResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
@@ -219,9 +327,9 @@ mod tests {
         let token = Token {
             t: TokenType::INT,
             value: "42".to_string(),
-            line: Some(1),
-            position: Some(2),
-            filename: Some("example.rs".to_string()),
+            span: Span::new(FileId(0), Location::new(1, 3), Location::new(1, 5), 2..4),
+            symbol: None,
+            trivia: None,
         };
 
         let error = ParserErrorBuilder::new(ParserErrorCode::UnexpectedToken, "Unexpected token")
@@ -231,6 +339,7 @@ mod tests {
         assert_eq!(error.code(), &ParserErrorCode::UnexpectedToken);
         assert_eq!(error.message(), "Unexpected token");
         assert_eq!(error.token(), Some(&token));
+        assert_eq!(error.span(), Some(&token.span));
         assert_eq!(error.at(), Some(1));
         assert_eq!(error.to(), None);
     }
@@ -240,9 +349,9 @@ mod tests {
         let token = Token {
             t: TokenType::IDENT,
             value: "foo".to_string(),
-            line: Some(1),
-            position: Some(2),
-            filename: Some("example.rs".to_string()),
+            span: Span::new(FileId(0), Location::new(1, 3), Location::new(1, 5), 2..4),
+            symbol: None,
+            trivia: None,
         };
 
         let mut error = ParserError::new(
@@ -262,9 +371,9 @@ mod tests {
         let new_token = Token {
             t: TokenType::ASSIGN,
             value: "=".to_string(),
-            line: Some(2),
-            position: Some(4),
-            filename: Some("example.rs".to_string()),
+            span: Span::new(FileId(0), Location::new(2, 5), Location::new(2, 6), 4..5),
+            symbol: None,
+            trivia: None,
         };
         error.set_token(Some(new_token.clone()));
         assert_eq!(error.token(), Some(&new_token));
@@ -5,40 +5,72 @@ pub mod precedence;
 use crate::{
     ast::{
         expressions::{
-            identifier_expression::Identifier, integer_literal::IntegerLiteral,
+            boolean_literal::BooleanLiteral,
+            char_literal::CharLiteral,
+            float_literal::FloatLiteral,
+            identifier_expression::Identifier,
+            if_expression::{ElseBranch, IfExpression},
+            infix_expression::InfixExpression,
+            integer_literal::IntegerLiteral,
             prefix_expression::PrefixExpression,
+            string_literal::StringLiteral,
+            template_literal::{TemplateLiteral, TemplatePart},
         },
         program::Program,
         statements::{
-            declare_statement::DeclareStatement, expression_statement::ExpressionStatement,
-            return_statement::ReturnStatement,
+            block_statement::BlockStatement, declare_statement::DeclareStatement,
+            expression_statement::ExpressionStatement, return_statement::ReturnStatement,
         },
     },
-    enums::{arithmetic::Arithmetic, keyword::Keyword, logicop::LogicOp, token_type::TokenType},
+    enums::{
+        arithmetic::Arithmetic, assign_op::AssignOp, bitop::Bitop, cmp::Cmp, keyword::Keyword,
+        logicop::LogicOp, number_suffix::NumberSuffix, token_type::TokenType,
+    },
+    interner::Symbol,
     lexer::Lexer,
     token::Token,
     traits::{Expression, Statement},
 };
 
-use self::{error::ParserError, precedence::Precedence};
+use self::{
+    error::ParserError,
+    precedence::{Associativity, Precedence},
+};
 
 type Result<T> = std::result::Result<T, ParserError>;
-type ExpressionParserFn<'a> = fn(&mut Parser<'a>) -> Result<Box<dyn Expression>>;
-
-pub struct Parser<'a> {
-    lexer: &'a mut Lexer,
+type ExpressionParserFn<'l, 's> = fn(&mut Parser<'l, 's>) -> Result<Box<dyn Expression>>;
+type InfixParserFn<'l, 's> =
+    fn(&mut Parser<'l, 's>, Box<dyn Expression>) -> Result<Box<dyn Expression>>;
+
+// 'l is the borrow of the lexer and 's the source it reads, kept apart so the lexer can be
+// inspected again once the parser is done with it.
+pub struct Parser<'l, 's> {
+    lexer: &'l mut Lexer<'s>,
     errors: Vec<ParserError>,
+    // How many of the lexer's errors have been moved into `errors` so far.
+    lex_errors: usize,
     current_token: Option<Token>,
     next_token: Option<Token>,
-    prefix_funs: HashMap<TokenType, ExpressionParserFn<'a>>,
-    infix_funs: HashMap<TokenType, ExpressionParserFn<'a>>,
+    prefix_funs: HashMap<TokenType, ExpressionParserFn<'l, 's>>,
+    infix_funs: HashMap<TokenType, InfixParserFn<'l, 's>>,
 }
 
-impl<'a> Parser<'a> {
-    pub fn new(lexer: &'a mut Lexer) -> Self {
-        let mut prefix_funs: HashMap<TokenType, ExpressionParserFn<'a>> = HashMap::new();
+impl<'l, 's> Parser<'l, 's> {
+    pub fn new(lexer: &'l mut Lexer<'s>) -> Self {
+        let mut prefix_funs: HashMap<TokenType, ExpressionParserFn<'l, 's>> = HashMap::new();
         prefix_funs.insert(TokenType::IDENT, Self::parse_identifier);
         prefix_funs.insert(TokenType::INT, Self::parse_integer_literal_expression);
+        prefix_funs.insert(TokenType::FLOAT, Self::parse_float_literal_expression);
+        prefix_funs.insert(TokenType::STRING, Self::parse_string_literal_expression);
+        prefix_funs.insert(TokenType::CHAR, Self::parse_char_literal_expression);
+        prefix_funs.insert(
+            TokenType::KEYWORD(Keyword::TRUE),
+            Self::parse_boolean_literal_expression,
+        );
+        prefix_funs.insert(
+            TokenType::KEYWORD(Keyword::FALSE),
+            Self::parse_boolean_literal_expression,
+        );
         prefix_funs.insert(
             TokenType::ARITHMETIC(Arithmetic::MINUS),
             Self::parse_prefix_expression,
@@ -47,14 +79,56 @@ impl<'a> Parser<'a> {
             TokenType::LOGICOP(LogicOp::NOT),
             Self::parse_prefix_expression,
         );
+        prefix_funs.insert(TokenType::BACKTICK, Self::parse_template_literal);
+        prefix_funs.insert(TokenType::LPAREN, Self::parse_grouped_expression);
+        prefix_funs.insert(TokenType::KEYWORD(Keyword::IF), Self::parse_if_expression);
+
+        let mut infix_funs: HashMap<TokenType, InfixParserFn<'l, 's>> = HashMap::new();
+        let infix_operators = [
+            TokenType::ASSIGN,
+            TokenType::ASSIGNOP(AssignOp::PLUS),
+            TokenType::ASSIGNOP(AssignOp::MINUS),
+            TokenType::ASSIGNOP(AssignOp::MUL),
+            TokenType::ASSIGNOP(AssignOp::DIV),
+            TokenType::ASSIGNOP(AssignOp::MOD),
+            TokenType::ASSIGNOP(AssignOp::POW),
+            TokenType::ASSIGNOP(AssignOp::AND),
+            TokenType::ASSIGNOP(AssignOp::OR),
+            TokenType::ASSIGNOP(AssignOp::XOR),
+            TokenType::ASSIGNOP(AssignOp::SHL),
+            TokenType::ASSIGNOP(AssignOp::SHR),
+            TokenType::ARITHMETIC(Arithmetic::PLUS),
+            TokenType::ARITHMETIC(Arithmetic::MINUS),
+            TokenType::ARITHMETIC(Arithmetic::MUL),
+            TokenType::ARITHMETIC(Arithmetic::DIV),
+            TokenType::ARITHMETIC(Arithmetic::MOD),
+            TokenType::ARITHMETIC(Arithmetic::POW),
+            TokenType::CMP(Cmp::GT),
+            TokenType::CMP(Cmp::LT),
+            TokenType::CMP(Cmp::GE),
+            TokenType::CMP(Cmp::LE),
+            TokenType::CMP(Cmp::EQUAL),
+            TokenType::CMP(Cmp::NEQUAL),
+            TokenType::BITOP(Bitop::AND),
+            TokenType::BITOP(Bitop::OR),
+            TokenType::BITOP(Bitop::XOR),
+            TokenType::BITOP(Bitop::SHL),
+            TokenType::BITOP(Bitop::SHR),
+            TokenType::LOGICOP(LogicOp::AND),
+            TokenType::LOGICOP(LogicOp::OR),
+        ];
+        for operator in infix_operators {
+            infix_funs.insert(operator, Self::parse_infix_expression);
+        }
 
         let mut new_parser = Self {
             lexer,
             errors: Vec::new(),
+            lex_errors: 0,
             current_token: None,
             next_token: None,
             prefix_funs,
-            infix_funs: HashMap::new(), // later
+            infix_funs,
         };
 
         new_parser.next_token();
@@ -64,7 +138,20 @@ impl<'a> Parser<'a> {
 
     pub fn next_token(&mut self) {
         self.current_token = self.next_token.clone();
-        self.next_token = Some(self.lexer.next_token());
+
+        // Doc comments are not attached to declarations yet, so the parser skips them.
+        let mut token = self.lexer.next_token();
+        while token.t == TokenType::DOC_COMMENT {
+            token = self.lexer.next_token();
+        }
+
+        self.next_token = Some(token);
+
+        // Report lexical problems alongside syntax errors, in the order they were found.
+        let lex_errors = &self.lexer.errors()[self.lex_errors..];
+        self.errors
+            .extend(lex_errors.iter().cloned().map(ParserError::lexical));
+        self.lex_errors = self.lexer.errors().len();
     }
 
     pub fn cmp_next_token_type(&self, token_type: TokenType) -> bool {
@@ -95,7 +182,7 @@ impl<'a> Parser<'a> {
                 let parsed_stmt = match self.parse_statement() {
                     Ok(stmt) => stmt,
                     Err(err) => {
-                        self.errors.push(err.into());
+                        self.errors.push(err);
                         return Err(ParserError::unexpected("An error occurred.".to_owned()));
                     }
                 };
@@ -107,6 +194,10 @@ impl<'a> Parser<'a> {
             }
         }
 
+        if !self.errors.is_empty() {
+            return Err(ParserError::unexpected("An error occurred.".to_owned()));
+        }
+
         Ok(new_program)
     }
 
@@ -118,17 +209,33 @@ impl<'a> Parser<'a> {
                         self.parse_declare_statement()
                     }
                     Keyword::RETURN => self.parse_return_statement(),
-                    Keyword::FOR => todo!(),
-                    Keyword::WHILE => todo!(),
-                    Keyword::FUNCTION => todo!(),
-                    Keyword::IF => todo!(),
-                    Keyword::ELSE => todo!(),
-                    Keyword::DO => todo!(),
-                    Keyword::END => todo!(),
-                    Keyword::TRUE => todo!(),
-                    Keyword::FALSE => todo!(),
-                    Keyword::UNDEFINED => todo!(),
+                    Keyword::IF => self.parse_expression_statement(),
+                    Keyword::ELSE => Err(ParserError::dangling_else(token.clone())),
+                    Keyword::TRUE | Keyword::FALSE => self.parse_expression_statement(),
+                    Keyword::FOR
+                    | Keyword::WHILE
+                    | Keyword::FUNCTION
+                    | Keyword::DO
+                    | Keyword::END
+                    | Keyword::MUT
+                    | Keyword::IN
+                    | Keyword::BREAK
+                    | Keyword::CONTINUE
+                    | Keyword::LOOP
+                    | Keyword::MATCH
+                    | Keyword::STRUCT
+                    | Keyword::ENUM
+                    | Keyword::IMPL
+                    | Keyword::TRAIT
+                    | Keyword::PUB
+                    | Keyword::USE
+                    | Keyword::MODULE
+                    | Keyword::AS
+                    | Keyword::SELF
+                    | Keyword::NULL
+                    | Keyword::UNDEFINED => Err(ParserError::unsupported_keyword(token.clone())),
                 },
+                TokenType::LBRACE => Ok(Box::new(self.parse_block_statement()?)),
                 _ => self.parse_expression_statement(),
             }
         } else {
@@ -142,26 +249,77 @@ impl<'a> Parser<'a> {
                 return Err(ParserError::unexpected_token(tok.clone(), TokenType::IDENT));
             }
 
-            Ok(Box::new(Identifier::new(tok.clone(), tok.t.to_string())))
+            Ok(Box::new(Self::identifier(tok)))
         } else {
             Err(ParserError::unexpected_eof())
         }
     }
 
+    fn identifier(token: &Token) -> Identifier {
+        // Tokens built outside of the lexer may not have been interned.
+        let symbol = token.symbol.unwrap_or_else(|| Symbol::intern(&token.value));
+
+        Identifier::new(token.clone(), symbol)
+    }
+
     fn parse_integer_literal_expression(&mut self) -> Result<Box<dyn Expression>> {
         if let Some(token) = &self.current_token {
-            match token.value.parse::<i32>() {
-                Ok(int) => {
-                    let integer = IntegerLiteral::new(token.clone(), int);
+            let (digits, suffix) = NumberSuffix::split(&token.value);
+            let max = suffix.unwrap_or(NumberSuffix::I32).max_literal();
+            match digits.parse::<u128>().ok().filter(|int| *int <= max) {
+                Some(int) => {
+                    let integer = IntegerLiteral::new(token.clone(), int, suffix);
                     Ok(Box::new(integer))
                 }
-                Err(_) => Err(ParserError::invalid_assignment_target()),
+                None => Err(ParserError::invalid_number(token.clone())),
+            }
+        } else {
+            Err(ParserError::unexpected_eof())
+        }
+    }
+
+    fn parse_float_literal_expression(&mut self) -> Result<Box<dyn Expression>> {
+        if let Some(token) = &self.current_token {
+            let (digits, _) = NumberSuffix::split(&token.value);
+            match digits.parse::<f64>() {
+                Ok(float) => Ok(Box::new(FloatLiteral::new(token.clone(), float))),
+                Err(_) => Err(ParserError::invalid_number(token.clone())),
+            }
+        } else {
+            Err(ParserError::unexpected_eof())
+        }
+    }
+
+    fn parse_string_literal_expression(&mut self) -> Result<Box<dyn Expression>> {
+        if let Some(token) = &self.current_token {
+            let string = StringLiteral::new(token.clone(), token.value.clone());
+            Ok(Box::new(string))
+        } else {
+            Err(ParserError::unexpected_eof())
+        }
+    }
+
+    fn parse_char_literal_expression(&mut self) -> Result<Box<dyn Expression>> {
+        if let Some(token) = &self.current_token {
+            let mut chars = token.value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Box::new(CharLiteral::new(token.clone(), c))),
+                _ => Err(ParserError::invalid_char(token.clone())),
             }
         } else {
             Err(ParserError::unexpected_eof())
         }
     }
 
+    fn parse_boolean_literal_expression(&mut self) -> Result<Box<dyn Expression>> {
+        if let Some(token) = &self.current_token {
+            let value = token.t == TokenType::KEYWORD(Keyword::TRUE);
+            Ok(Box::new(BooleanLiteral::new(token.clone(), value)))
+        } else {
+            Err(ParserError::unexpected_eof())
+        }
+    }
+
     fn parse_prefix_expression(&mut self) -> Result<Box<dyn Expression>> {
         if let Some(token) = &self.current_token.clone() {
             self.next_token();
@@ -177,6 +335,192 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_infix_expression(&mut self, lhs: Box<dyn Expression>) -> Result<Box<dyn Expression>> {
+        let token = match &self.current_token {
+            Some(token) => token.clone(),
+            None => return Err(ParserError::unexpected_eof()),
+        };
+
+        let precedence = Precedence::of(&token.t);
+        if precedence == Precedence::Assign && !lhs.is_assignable() {
+            let mut error = ParserError::invalid_assignment_target();
+            error.set_span(Some(token.span.clone()));
+            error.set_token(Some(token));
+            return Err(error);
+        }
+
+        self.next_token();
+        let rhs = self.parse_expression(precedence)?;
+
+        if precedence.associativity() == Associativity::None && self.next_precedence() == precedence
+        {
+            let next = self.next_token.clone().unwrap();
+            return Err(ParserError::non_associative_operator(next, &token.value));
+        }
+
+        Ok(Box::new(InfixExpression::new(
+            token.clone(),
+            lhs,
+            token.value,
+            rhs,
+        )))
+    }
+
+    fn parse_grouped_expression(&mut self) -> Result<Box<dyn Expression>> {
+        // LPAREN EXPRESSION RPAREN
+        self.next_token();
+        let expression = self.parse_expression(Precedence::Lowest)?;
+
+        match &self.next_token {
+            Some(token) if token.t == TokenType::RPAREN => {
+                self.next_token();
+                Ok(expression)
+            }
+            Some(token) => Err(ParserError::unexpected_token(
+                token.clone(),
+                TokenType::RPAREN,
+            )),
+            None => Err(ParserError::unexpected_eof()),
+        }
+    }
+
+    fn parse_if_expression(&mut self) -> Result<Box<dyn Expression>> {
+        Ok(Box::new(self.parse_if()?))
+    }
+
+    fn parse_if(&mut self) -> Result<IfExpression> {
+        // IF EXPRESSION BLOCK
+        // IF EXPRESSION BLOCK ELSE BLOCK
+        // IF EXPRESSION BLOCK ELSE IF ...
+        let token = match &self.current_token {
+            Some(token) => token.clone(),
+            None => return Err(ParserError::unexpected_eof()),
+        };
+
+        // Parentheses around the condition are a grouped expression.
+        self.next_token();
+        let condition = self.parse_expression(Precedence::Lowest)?;
+
+        self.expect_next(TokenType::LBRACE)?;
+        let consequence = self.parse_block_statement()?;
+
+        let mut alternative = None;
+        if self.cmp_next_token_type(TokenType::KEYWORD(Keyword::ELSE)) {
+            self.next_token();
+
+            if self.cmp_next_token_type(TokenType::KEYWORD(Keyword::IF)) {
+                self.next_token();
+                alternative = Some(ElseBranch::If(Box::new(self.parse_if()?)));
+            } else {
+                self.expect_next(TokenType::LBRACE)?;
+                alternative = Some(ElseBranch::Block(self.parse_block_statement()?));
+            }
+        }
+
+        Ok(IfExpression::new(
+            token,
+            condition,
+            consequence,
+            alternative,
+        ))
+    }
+
+    fn parse_block_statement(&mut self) -> Result<BlockStatement> {
+        // LBRACE STATEMENT* EXPRESSION? RBRACE
+        let token = match &self.current_token {
+            Some(token) => token.clone(),
+            None => return Err(ParserError::unexpected_eof()),
+        };
+
+        let mut statements: Vec<Box<dyn Statement>> = Vec::new();
+        let mut value = None;
+
+        self.next_token();
+
+        loop {
+            let current = match &self.current_token {
+                Some(current) => current.clone(),
+                None => return Err(ParserError::unexpected_eof()),
+            };
+
+            match current.t {
+                TokenType::RBRACE => break,
+                TokenType::EOF => {
+                    return Err(ParserError::unexpected_token(current, TokenType::RBRACE))
+                }
+                _ => {}
+            }
+
+            if !self.prefix_funs.contains_key(&current.t) {
+                statements.push(self.parse_statement()?);
+                self.next_token();
+                continue;
+            }
+
+            // An expression right before the closing brace is the value of the block.
+            let expression = self.parse_statement_expression()?;
+            if self.cmp_next_token_type(TokenType::RBRACE) {
+                value = Some(expression);
+                self.next_token();
+                break;
+            }
+
+            let stmt = ExpressionStatement::new(self.current_token.clone().unwrap(), expression);
+            if self.cmp_next_token_type(TokenType::SEMICOLON) {
+                self.next_token();
+            }
+
+            statements.push(Box::new(stmt));
+            self.next_token();
+        }
+
+        Ok(BlockStatement::new(token, statements, value))
+    }
+
+    fn parse_template_literal(&mut self) -> Result<Box<dyn Expression>> {
+        // BACKTICK (TEMPLATE_STRING | INTERP_START EXPRESSION INTERP_END)* BACKTICK
+        let token = match &self.current_token {
+            Some(token) => token.clone(),
+            None => return Err(ParserError::unexpected_eof()),
+        };
+
+        let mut parts = Vec::new();
+
+        loop {
+            self.next_token();
+
+            let current = match &self.current_token {
+                Some(current) => current.clone(),
+                None => return Err(ParserError::unexpected_eof()),
+            };
+
+            match current.t {
+                TokenType::BACKTICK => break,
+                TokenType::TEMPLATE_STRING => parts.push(TemplatePart::Text(current.value)),
+                TokenType::INTERP_START => {
+                    self.next_token();
+                    let expression = self.parse_expression(Precedence::Lowest)?;
+                    parts.push(TemplatePart::Expression(expression));
+
+                    if !self.cmp_next_token_type(TokenType::INTERP_END) {
+                        return match &self.next_token {
+                            Some(next) => Err(ParserError::unexpected_token(
+                                next.clone(),
+                                TokenType::INTERP_END,
+                            )),
+                            None => Err(ParserError::unexpected_eof()),
+                        };
+                    }
+
+                    self.next_token();
+                }
+                _ => return Err(ParserError::unexpected_token(current, TokenType::BACKTICK)),
+            }
+        }
+
+        Ok(Box::new(TemplateLiteral::new(token, parts)))
+    }
+
     fn parse_type_specifier(&mut self) -> Option<Token> {
         if self.cmp_next_token_type(TokenType::COLON) {
             self.next_token();
@@ -198,11 +542,15 @@ impl<'a> Parser<'a> {
 
         let identifier: Option<Box<dyn Expression>>;
         if let Some(tok) = &self.next_token {
+            if tok.t == TokenType::KEYWORD(Keyword::MUT) {
+                return Err(ParserError::unsupported_keyword(tok.clone()));
+            }
+
             if tok.t != TokenType::IDENT {
                 return Err(ParserError::unexpected_token(tok.clone(), TokenType::IDENT));
             }
 
-            identifier = Some(Box::new(Identifier::new(tok.clone(), tok.t.to_string())))
+            identifier = Some(Box::new(Self::identifier(tok)))
         } else {
             return Err(ParserError::unexpected_eof());
         }
@@ -227,7 +575,7 @@ impl<'a> Parser<'a> {
         }
 
         if !self.cmp_next_token_type(TokenType::ASSIGN) {
-            if let Some(_) = &self.next_token {
+            if self.next_token.is_some() {
                 return Err(ParserError::unexpected_token(
                     self.next_token.clone().unwrap(),
                     TokenType::ASSIGN,
@@ -237,65 +585,48 @@ impl<'a> Parser<'a> {
             }
         }
 
+        self.next_token();
         self.next_token();
 
-        loop {
-            if let Some(_) = &self.current_token {
-                if self.cmp_current_token_type(TokenType::SEMICOLON) {
-                    break;
-                }
+        stmt.value = Some(self.parse_expression(Precedence::Lowest)?);
+        self.expect_semicolon()?;
 
-                if self.cmp_current_token_type(TokenType::EOF)
-                    || self.cmp_current_token_type(TokenType::ILLEGAL)
-                {
-                    if let Some(_) = &self.current_token {
-                        return Err(ParserError::unexpected_token(
-                            self.current_token.clone().unwrap(),
-                            TokenType::SEMICOLON,
-                        ));
-                    } else {
-                        return Err(ParserError::unexpected_eof());
-                    }
-                }
+        Ok(Box::new(stmt))
+    }
 
-                self.next_token();
-            } else {
-                break;
-            }
+    fn parse_return_statement(&mut self) -> Result<Box<dyn Statement>> {
+        // RETURN SEMICOLON
+        // RETURN EXPRESSION SEMICOLON
+        let token = self.current_token.clone().unwrap();
+
+        if self.cmp_next_token_type(TokenType::SEMICOLON) {
+            self.next_token();
+            return Ok(Box::new(ReturnStatement::new(token, None)));
         }
 
-        return Ok(Box::new(stmt));
-    }
+        self.next_token();
 
-    fn parse_return_statement(&mut self) -> Result<Box<dyn Statement>> {
-        loop {
-            if let Some(_) = &self.current_token {
-                if self.cmp_current_token_type(TokenType::SEMICOLON) {
-                    break;
-                }
+        let value = self.parse_expression(Precedence::Lowest)?;
+        self.expect_semicolon()?;
 
-                if self.cmp_current_token_type(TokenType::EOF)
-                    || self.cmp_current_token_type(TokenType::ILLEGAL)
-                {
-                    if let Some(_) = &self.current_token {
-                        return Err(ParserError::unexpected_token(
-                            self.current_token.clone().unwrap(),
-                            TokenType::SEMICOLON,
-                        ));
-                    } else {
-                        return Err(ParserError::unexpected_eof());
-                    }
-                }
+        Ok(Box::new(ReturnStatement::new(token, Some(value))))
+    }
+
+    /// Moves onto the semicolon ending a statement, which must be the next token.
+    fn expect_semicolon(&mut self) -> Result<()> {
+        self.expect_next(TokenType::SEMICOLON)
+    }
 
+    /// Moves onto the next token, which must be of type `t`.
+    fn expect_next(&mut self, t: TokenType) -> Result<()> {
+        match &self.next_token {
+            Some(token) if token.t == t => {
                 self.next_token();
-            } else {
-                break;
+                Ok(())
             }
+            Some(token) => Err(ParserError::unexpected_token(token.clone(), t)),
+            None => Err(ParserError::unexpected_eof()),
         }
-
-        let stmt = ReturnStatement::new(self.current_token.clone().unwrap(), None);
-
-        return Ok(Box::new(stmt));
     }
 
     fn parse_expression_statement(&mut self) -> Result<Box<dyn Statement>> {
@@ -303,29 +634,62 @@ impl<'a> Parser<'a> {
             return Err(ParserError::unexpected_eof());
         }
 
-        let expression = self.parse_expression(Precedence::Lowest)?;
+        let expression = self.parse_statement_expression()?;
         let stmt = ExpressionStatement::new(self.current_token.clone().unwrap(), expression);
 
         if self.cmp_next_token_type(TokenType::SEMICOLON) {
             self.next_token();
         }
 
-        return Ok(Box::new(stmt));
+        Ok(Box::new(stmt))
     }
 
-    fn parse_expression(&mut self, _precedence: Precedence) -> Result<Box<dyn Expression>> {
-        if let Some(token) = &self.current_token {
-            if let Some(fun) = self
-                .prefix_funs
-                .get(&token.t)
-            {
-                return fun(self);
-            }else {
-                Err(ParserError::invalid_expression())
-            }
-        } else {
-            Err(ParserError::unexpected_eof())
+    /// Parses the expression of an expression statement. As in Rust, an `if` in statement
+    /// position ends the statement, so an operator on the next line starts a new one.
+    fn parse_statement_expression(&mut self) -> Result<Box<dyn Expression>> {
+        if self.cmp_current_token_type(TokenType::KEYWORD(Keyword::IF)) {
+            return self.parse_if_expression();
         }
+
+        self.parse_expression(Precedence::Lowest)
+    }
+
+    fn parse_expression(&mut self, precedence: Precedence) -> Result<Box<dyn Expression>> {
+        let prefix = match &self.current_token {
+            Some(token) => match self.prefix_funs.get(&token.t) {
+                Some(fun) => *fun,
+                None => return Err(ParserError::invalid_expression(token.clone())),
+            },
+            None => return Err(ParserError::unexpected_eof()),
+        };
+
+        let mut lhs = prefix(self)?;
+
+        // Operators binding tighter than `precedence` take what was parsed so far as their lhs,
+        // as do right-associative ones binding as tight.
+        while precedence < self.next_precedence()
+            || (precedence == self.next_precedence()
+                && precedence.associativity() == Associativity::Right)
+        {
+            let infix = match &self.next_token {
+                Some(token) => match self.infix_funs.get(&token.t) {
+                    Some(fun) => *fun,
+                    None => return Ok(lhs),
+                },
+                None => return Ok(lhs),
+            };
+
+            self.next_token();
+            lhs = infix(self, lhs)?;
+        }
+
+        Ok(lhs)
+    }
+
+    fn next_precedence(&self) -> Precedence {
+        self.next_token
+            .as_ref()
+            .map_or(Precedence::Lowest, |token| Precedence::of(&token.t))
     }
 
     pub fn errors(&self) -> &[ParserError] {
@@ -337,14 +701,32 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Parses `code` and renders each statement back to source, for the AST tests.
+#[cfg(test)]
+pub(crate) fn parse_statements(code: &str) -> Vec<String> {
+    let mut lexer = Lexer::new(code);
+    let mut parser = Parser::new(&mut lexer);
+    let program = parser.parse();
+
+    assert!(program.is_ok(), "{:?}", parser.errors());
+
+    program
+        .unwrap()
+        .statements
+        .iter()
+        .map(|statement| statement.to_string())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{lexer::error::LexErrorCode, parser::error::ParserErrorCode};
 
     #[test]
     fn test_parse_success() {
         let code = "let x = 5;";
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let result = parser.parse();
 
@@ -353,13 +735,199 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
     }
 
+    #[test]
+    fn test_parse_skips_doc_comments() {
+        let code = "/// The answer.\nlet x = 42; // not a doc comment";
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+        let result = parser.parse();
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().statements.len(), 1);
+    }
+
     #[test]
     fn test_parse_error() {
         let code = "let x 5;";
-        let mut lexer = Lexer::new(code.chars().collect());
+        let mut lexer = Lexer::new(code);
         let mut parser = Parser::new(&mut lexer);
         let result = parser.parse();
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_error_span() {
+        let code = "let x 5;";
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err());
+
+        let span = parser.errors()[0].span().unwrap();
+        assert_eq!(span.underline(code), "let x 5;\n      ^");
+    }
+
+    #[test]
+    fn test_invalid_expression_span() {
+        let tests = [
+            ("let x = 1 + ;", "let x = 1 + ;\n            ^"),
+            ("a => b;", "a => b;\n  ^^"),
+            ("let x = { 1 };", "let x = { 1 };\n        ^"),
+        ];
+
+        for (code, underline) in tests {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+
+            let error = &parser.errors()[0];
+            assert_eq!(
+                error.code(),
+                &ParserErrorCode::InvalidExpression,
+                "{}",
+                code
+            );
+            assert_eq!(error.span().unwrap().underline(code), underline, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_parse_reports_lex_errors() {
+        let tests = [
+            ("let x = 300u8;", LexErrorCode::IntegerOverflow),
+            ("let x = 3000000000;", LexErrorCode::IntegerOverflow),
+            ("let x = 256u8;", LexErrorCode::IntegerOverflow),
+            ("let s = \"a\\qb\";", LexErrorCode::InvalidEscape),
+            ("let s = \"abc;", LexErrorCode::UnterminatedString),
+        ];
+
+        for (code, expected) in tests {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+
+            let error = &parser.errors()[0];
+            assert_eq!(error.code(), &ParserErrorCode::Lexical, "{}", code);
+            assert_eq!(error.lex_error().unwrap().code(), &expected, "{}", code);
+            assert!(error.span().is_some(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_lexer_outlives_parser_borrow() {
+        let mut lexer = Lexer::new("let p\u{430}ypal = 1;");
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_ok());
+
+        assert!(lexer.errors().is_empty());
+        assert_eq!(lexer.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_unsupported_keywords() {
+        let keywords = [
+            "mut", "in", "break", "continue", "loop", "match", "struct", "enum", "impl", "trait",
+            "pub", "use", "mod", "as", "self", "null", "nil", "for", "while", "fn", "do", "end",
+        ];
+
+        for keyword in keywords {
+            let code = format!("{} x;", keyword);
+            let mut lexer = Lexer::new(&code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err());
+
+            let error = &parser.errors()[0];
+            assert_eq!(error.code(), &ParserErrorCode::UnsupportedKeyword);
+            assert_eq!(
+                error.message(),
+                format!("Keyword '{}' is not supported yet", keyword)
+            );
+            assert_eq!(error.token().unwrap().value, keyword);
+        }
+    }
+
+    #[test]
+    fn test_parse_unsupported_keywords_in_blocks() {
+        let tests = [
+            ("if x { while y {} }", "while"),
+            ("{ fn f() {} }", "fn"),
+            ("fn main() {}", "fn"),
+            ("if x { 1 } else { for i in xs {} }", "for"),
+        ];
+
+        for (code, keyword) in tests {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+
+            assert!(parser.parse().is_err(), "{}", code);
+
+            let error = &parser.errors()[0];
+            assert_eq!(
+                error.code(),
+                &ParserErrorCode::UnsupportedKeyword,
+                "{}",
+                code
+            );
+            assert_eq!(error.token().unwrap().value, keyword, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_parse_unsupported_keyword_in_declaration() {
+        let mut lexer = Lexer::new("let mut y = 5;");
+        let mut parser = Parser::new(&mut lexer);
+
+        assert!(parser.parse().is_err());
+
+        let error = &parser.errors()[0];
+        assert_eq!(error.code(), &ParserErrorCode::UnsupportedKeyword);
+        assert_eq!(error.message(), "Keyword 'mut' is not supported yet");
+    }
+
+    #[test]
+    fn test_if_expression_block_values() {
+        // (code, condition, consequence value, else block value)
+        let tests = [
+            ("if 1 < 2 { 1 } else { 2 }", "(1 < 2)", Some("1"), Some("2")),
+            (
+                "if x == y { a + b } else { c }",
+                "(x == y)",
+                Some("(a + b)"),
+                Some("c"),
+            ),
+            ("if true { let x = 1; x; }", "true", None, None),
+            (
+                "if a { if b { 1 } else { 2 } }",
+                "a",
+                Some("if b { 1 } else { 2 }"),
+                None,
+            ),
+        ];
+
+        for (code, condition, consequence, alternative) in tests {
+            let mut lexer = Lexer::new(code);
+            let mut parser = Parser::new(&mut lexer);
+            let expression = parser.parse_if().unwrap();
+
+            let value = |block: &BlockStatement| block.value.as_ref().map(|v| v.to_string());
+            let alternative_value = match &expression.alternative {
+                Some(ElseBranch::Block(block)) => value(block),
+                _ => None,
+            };
+
+            assert_eq!(expression.condition.to_string(), condition, "{}", code);
+            assert_eq!(
+                value(&expression.consequence).as_deref(),
+                consequence,
+                "{}",
+                code
+            );
+            assert_eq!(alternative_value.as_deref(), alternative, "{}", code);
+        }
+    }
 }
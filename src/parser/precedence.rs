@@ -1,22 +1,85 @@
-#[derive(Debug, Clone)]
+use crate::enums::{
+    arithmetic::Arithmetic, bitop::Bitop, cmp::Cmp, logicop::LogicOp, token_type::TokenType,
+};
+
+/// How operators of the same precedence group when chained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,  // `a - b - c` is `(a - b) - c`
+    Right, // `a = b = c` is `a = (b = c)`
+    None,  // `a < b < c` is an error
+}
+
+/// Binding power of operators, from the loosest to the tightest.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Precedence {
     Lowest = 1,
+    Assign,
+    LogicalOr,
+    LogicalAnd,
     EQ,
     LGT,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
     Sum,
     Product,
+    Power,
     Prefix,
     Call,
 }
 
+impl Precedence {
+    /// Returns the precedence of `t` as an infix operator, `Lowest` for any other token.
+    pub fn of(t: &TokenType) -> Precedence {
+        match t {
+            TokenType::ASSIGN | TokenType::ASSIGNOP(_) => Precedence::Assign,
+            TokenType::LOGICOP(LogicOp::OR) => Precedence::LogicalOr,
+            TokenType::LOGICOP(LogicOp::AND) => Precedence::LogicalAnd,
+            TokenType::CMP(Cmp::EQUAL | Cmp::NEQUAL) => Precedence::EQ,
+            TokenType::CMP(Cmp::GT | Cmp::LT | Cmp::GE | Cmp::LE) => Precedence::LGT,
+            TokenType::BITOP(Bitop::OR) => Precedence::BitOr,
+            TokenType::BITOP(Bitop::XOR) => Precedence::BitXor,
+            TokenType::BITOP(Bitop::AND) => Precedence::BitAnd,
+            TokenType::BITOP(Bitop::SHL | Bitop::SHR) => Precedence::Shift,
+            TokenType::ARITHMETIC(Arithmetic::PLUS | Arithmetic::MINUS) => Precedence::Sum,
+            TokenType::ARITHMETIC(Arithmetic::MUL | Arithmetic::DIV | Arithmetic::MOD) => {
+                Precedence::Product
+            }
+            TokenType::ARITHMETIC(Arithmetic::POW) => Precedence::Power,
+            _ => Precedence::Lowest,
+        }
+    }
+
+    /// Returns how the operators of this precedence chain.
+    pub fn associativity(&self) -> Associativity {
+        match self {
+            Precedence::Assign | Precedence::Power => Associativity::Right,
+            Precedence::EQ | Precedence::LGT => Associativity::None,
+            _ => Associativity::Left,
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Precedence {
     fn to_string(&self) -> String {
         match self {
             Precedence::Lowest => "Lowest".to_owned(),
+            Precedence::Assign => "Assign".to_owned(),
+            Precedence::LogicalOr => "LogicalOr".to_owned(),
+            Precedence::LogicalAnd => "LogicalAnd".to_owned(),
             Precedence::EQ => "EQ".to_owned(),
             Precedence::LGT => "LGT".to_owned(),
+            Precedence::BitOr => "BitOr".to_owned(),
+            Precedence::BitXor => "BitXor".to_owned(),
+            Precedence::BitAnd => "BitAnd".to_owned(),
+            Precedence::Shift => "Shift".to_owned(),
             Precedence::Sum => "sum".to_owned(),
             Precedence::Product => "Product".to_owned(),
+            Precedence::Power => "Power".to_owned(),
             Precedence::Prefix => "Prefix".to_owned(),
             Precedence::Call => "Call".to_owned(),
         }
@@ -1,11 +1,15 @@
 use std::io::{stdout, Write};
 
-use crate::{enums::token_type::TokenType, lexer::Lexer, queue::Queue, token::Token};
+use crate::{
+    enums::token_type::TokenType, lexer::Lexer, queue::Queue, source_map::SourceMap, token::Token,
+};
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct REPL {
     tokens: Queue<Token>,
     buffer: String,
     index: usize,
+    source_map: SourceMap,
 }
 
 impl REPL {
@@ -14,6 +18,7 @@ impl REPL {
             tokens: Queue::new(),
             buffer: String::new(),
             index: 1,
+            source_map: SourceMap::new(),
         }
     }
 
@@ -40,29 +45,33 @@ impl REPL {
 
         let bytes_read = std::io::stdin().read_line(&mut self.buffer);
 
-        return bytes_read.is_ok();
+        bytes_read.is_ok()
     }
 
     fn eval(&mut self) {
-        let mut lexer = Lexer::new(self.buffer.chars().collect());
+        let file_id = self
+            .source_map
+            .add_file(format!("<repl:{}>", self.index - 1), self.buffer.as_str());
+        let mut lexer = Lexer::for_file(&self.source_map, file_id);
 
-        loop {
-            let token = lexer.next_token();
+        for token in lexer.by_ref() {
             match token.t {
-                TokenType::ILLEGAL => {
-                    panic!("ILLEGAL TOKEN ENCOUNTERED");
-                }
-                TokenType::EOF => {
-                    println!("End of file (EOF)");
-                    break;
-                }
+                TokenType::EOF => println!("End of file (EOF)"),
                 _ => self.tokens.enqueue(token),
             }
         }
+
+        for error in lexer.errors().iter().chain(lexer.warnings()) {
+            println!(
+                "{}",
+                self.source_map
+                    .format_diagnostic(&error.to_string(), error.span())
+            );
+        }
     }
 
     fn print(&mut self) {
-        let tokens: Vec<Token> = self.tokens.clone().into_iter().collect();
+        let tokens: Vec<Token> = self.tokens.clone().collect();
 
         for t in tokens {
             println!("Type: {:?}, Literal: {}", t.t, t.value);
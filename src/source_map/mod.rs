@@ -0,0 +1,173 @@
+use crate::span::{FileId, Location, Span};
+
+/// A registered file (or REPL input) along with the byte offset of each of its lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    id: FileId,
+    name: String,
+    source: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    pub fn new(id: FileId, name: String, source: String) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            id,
+            name,
+            source,
+            line_starts,
+        }
+    }
+
+    pub fn id(&self) -> FileId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn source(&self) -> &str {
+        self.source.as_ref()
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Translates a byte offset into a 1-based line and column.
+    pub fn location(&self, offset: usize) -> Option<Location> {
+        if offset > self.source.len() || !self.source.is_char_boundary(offset) {
+            return None;
+        }
+
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let column = self.source[self.line_starts[line]..offset].chars().count() + 1;
+
+        Some(Location::new(line + 1, column))
+    }
+
+    /// Returns the text of a 1-based line, without its line ending.
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.source.len());
+
+        Some(self.source[start..end].trim_end_matches(['\n', '\r']))
+    }
+}
+
+/// Registry of every source the interpreter has seen, handing out the `FileId`s stored in spans.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self { files: Vec::new() }
+    }
+
+    pub fn add_file(&mut self, name: impl Into<String>, source: impl Into<String>) -> FileId {
+        let id = FileId(self.files.len() as u32);
+        self.files
+            .push(SourceFile::new(id, name.into(), source.into()));
+        id
+    }
+
+    pub fn get(&self, id: FileId) -> Option<&SourceFile> {
+        self.files.get(id.0 as usize)
+    }
+
+    pub fn files(&self) -> &[SourceFile] {
+        self.files.as_ref()
+    }
+
+    pub fn location(&self, id: FileId, offset: usize) -> Option<Location> {
+        self.get(id)?.location(offset)
+    }
+
+    pub fn line(&self, id: FileId, line: usize) -> Option<&str> {
+        self.get(id)?.line(line)
+    }
+
+    /// Renders `message` followed by the file position of `span` and the underlined source.
+    pub fn format_diagnostic(&self, message: &str, span: &Span) -> String {
+        match self.get(span.file_id) {
+            Some(file) => format!(
+                "{}\n --> {}:{}:{}\n{}",
+                message,
+                file.name(),
+                span.start.line,
+                span.start.column,
+                span.underline(file.source())
+            ),
+            None => message.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_file() {
+        let mut source_map = SourceMap::new();
+
+        let add = source_map.add_file("data/add.zpp", "let five = 5;");
+        let repl = source_map.add_file("<repl:1>", "five + 1");
+
+        assert_eq!(add, FileId(0));
+        assert_eq!(repl, FileId(1));
+        assert_eq!(source_map.get(repl).unwrap().name(), "<repl:1>");
+        assert_eq!(source_map.get(add).unwrap().source(), "let five = 5;");
+        assert!(source_map.get(FileId(2)).is_none());
+    }
+
+    #[test]
+    fn test_location() {
+        let mut source_map = SourceMap::new();
+        let id = source_map.add_file("test.zpp", "let é = 1;\r\nlet y;\n");
+
+        assert_eq!(source_map.location(id, 0), Some(Location::new(1, 1)));
+        assert_eq!(source_map.location(id, 7), Some(Location::new(1, 7)));
+        assert_eq!(source_map.location(id, 13), Some(Location::new(2, 1)));
+        assert_eq!(source_map.location(id, 19), Some(Location::new(2, 7)));
+        assert_eq!(source_map.location(id, 20), Some(Location::new(3, 1)));
+        assert_eq!(source_map.location(id, 5), None);
+        assert_eq!(source_map.location(id, 21), None);
+    }
+
+    #[test]
+    fn test_line() {
+        let mut source_map = SourceMap::new();
+        let id = source_map.add_file("test.zpp", "let x = 1;\r\nlet y;\n");
+
+        assert_eq!(source_map.get(id).unwrap().line_count(), 3);
+        assert_eq!(source_map.line(id, 1), Some("let x = 1;"));
+        assert_eq!(source_map.line(id, 2), Some("let y;"));
+        assert_eq!(source_map.line(id, 3), Some(""));
+        assert_eq!(source_map.line(id, 0), None);
+        assert_eq!(source_map.line(id, 4), None);
+    }
+
+    #[test]
+    fn test_format_diagnostic() {
+        let mut source_map = SourceMap::new();
+        let id = source_map.add_file("test.zpp", "let x = 5;\nlet y 10;");
+        let span = Span::new(id, Location::new(2, 7), Location::new(2, 9), 17..19);
+
+        assert_eq!(
+            source_map.format_diagnostic("Unexpected token", &span),
+            "Unexpected token\n --> test.zpp:2:7\nlet y 10;\n      ^^"
+        );
+    }
+}
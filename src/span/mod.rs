@@ -0,0 +1,105 @@
+use std::ops::Range;
+
+/// Identifies the source file a span points into.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FileId(pub u32);
+
+/// A 1-based line and column, the column being counted in characters.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A region of source code, `end` and the end of `byte_range` being exclusive.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub file_id: FileId,
+    pub start: Location,
+    pub end: Location,
+    pub byte_range: Range<usize>,
+}
+
+impl Span {
+    pub fn new(file_id: FileId, start: Location, end: Location, byte_range: Range<usize>) -> Self {
+        Self {
+            file_id,
+            start,
+            end,
+            byte_range,
+        }
+    }
+
+    /// Returns the smallest span covering both `self` and `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            file_id: self.file_id,
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+            byte_range: self.byte_range.start.min(other.byte_range.start)
+                ..self.byte_range.end.max(other.byte_range.end),
+        }
+    }
+
+    /// Renders the first line of the span followed by a line of carets under it.
+    pub fn underline(&self, source: &str) -> String {
+        let line = source
+            .lines()
+            .nth(self.start.line.saturating_sub(1))
+            .unwrap_or("");
+        let width = line.chars().count();
+
+        let start = self.start.column.saturating_sub(1).min(width);
+        let end = if self.end.line == self.start.line {
+            self.end.column.saturating_sub(1).min(width)
+        } else {
+            width
+        };
+
+        format!(
+            "{}\n{}{}",
+            line,
+            " ".repeat(start),
+            "^".repeat(end.saturating_sub(start).max(1))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_to() {
+        let lhs = Span::new(FileId(0), Location::new(1, 5), Location::new(1, 6), 4..5);
+        let rhs = Span::new(FileId(0), Location::new(2, 1), Location::new(2, 3), 10..12);
+
+        let span = lhs.to(&rhs);
+
+        assert_eq!(span.start, Location::new(1, 5));
+        assert_eq!(span.end, Location::new(2, 3));
+        assert_eq!(span.byte_range, 4..12);
+    }
+
+    #[test]
+    fn test_span_underline() {
+        let source = "let x = 5;\nlet y 10;";
+        let span = Span::new(FileId(0), Location::new(2, 7), Location::new(2, 9), 17..19);
+
+        assert_eq!(span.underline(source), "let y 10;\n      ^^");
+    }
+
+    #[test]
+    fn test_empty_span_underline() {
+        let source = "let x = ";
+        let span = Span::new(FileId(0), Location::new(1, 9), Location::new(1, 9), 8..8);
+
+        assert_eq!(span.underline(source), "let x = \n        ^");
+    }
+}
@@ -1,29 +1,98 @@
-use crate::enums::token_type::TokenType;
+use std::borrow::Cow;
+
+use crate::{enums::token_type::TokenType, interner::Symbol, span::Span};
 
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub struct Token {
     // t stands for type. which is a token_type word in rust :/
     pub t: TokenType,
     pub value: String,
-    pub line: Option<usize>,
-    pub position: Option<usize>,
-    pub filename: Option<String>,
+    pub span: Span,
+    // The interned name of identifiers.
+    pub symbol: Option<Symbol>,
+    // Only set by lexers in trivia mode, see `Lexer::with_trivia`.
+    pub trivia: Option<Box<TokenTrivia>>,
 }
 
 impl Token {
-    pub fn new(
-        t: TokenType,
-        value: &str,
-        line: Option<usize>,
-        position: Option<usize>,
-        filename: Option<String>,
-    ) -> Token {
+    pub fn new(t: TokenType, value: &str, span: Span) -> Token {
         Token {
             t,
             value: value.to_string(),
-            line,
-            position,
-            filename,
+            span,
+            symbol: (t == TokenType::IDENT).then(|| Symbol::intern(value)),
+            trivia: None,
+        }
+    }
+
+    /// Returns the exact source of the token along with its trivia, or an empty string when it
+    /// was lexed without trivia.
+    pub fn to_source(&self) -> String {
+        self.trivia
+            .as_ref()
+            .map_or(String::new(), |trivia| trivia.to_source())
+    }
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TriviaKind {
+    WHITESPACE,    // Represents spaces, tabs and line breaks
+    LINE_COMMENT,  // Represents a // comment, without its line break
+    BLOCK_COMMENT, // Represents a /* */ comment
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub text: String,
+}
+
+/// The source text of a token and the whitespace and comments around it. Trailing trivia runs
+/// up to the end of the token's line, the next line break starting the next token's leading
+/// trivia.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TokenTrivia {
+    pub leading: Vec<Trivia>,
+    pub text: String,
+    pub trailing: Vec<Trivia>,
+}
+
+impl TokenTrivia {
+    pub fn to_source(&self) -> String {
+        let mut source = String::new();
+
+        for trivia in &self.leading {
+            source.push_str(&trivia.text);
+        }
+        source.push_str(&self.text);
+        for trivia in &self.trailing {
+            source.push_str(&trivia.text);
+        }
+
+        source
+    }
+}
+
+/// A token borrowing from the lexer's input. `text` is the exact source slice, while `value`
+/// only owns a copy when it differs from it (escaped strings, normalised numbers, EOF).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lexeme<'a> {
+    pub t: TokenType,
+    pub text: &'a str,
+    pub value: Cow<'a, str>,
+    pub span: Span,
+    pub symbol: Option<Symbol>,
+}
+
+impl From<Lexeme<'_>> for Token {
+    fn from(lexeme: Lexeme<'_>) -> Self {
+        Token {
+            t: lexeme.t,
+            value: lexeme.value.into_owned(),
+            span: lexeme.span,
+            symbol: lexeme.symbol,
+            trivia: None,
         }
     }
 }
@@ -17,6 +17,11 @@ impl core::fmt::Debug for dyn Statement {
 
 pub trait Expression: Node {
     fn eval(&self) -> String;
+
+    /// Whether the expression can appear on the left of an assignment.
+    fn is_assignable(&self) -> bool {
+        false
+    }
 }
 
 impl core::fmt::Debug for dyn Expression {